@@ -126,10 +126,14 @@ fn on_player_inserted(_ctx: &EventContext, player: &Player) {
     }
 }
 
-fn on_player_update(_ctx: &EventContext, old_player: &Player, new_player: &Player) {
-    println!("PLAYER UPDATED New x-Pos {}", new_player.position.x);
+fn on_transform_update(ctx: &EventContext, _old: &Transform, new: &Transform) {
+    // Only players are rendered for now, find the one this entity belongs to
+    let Some(player) = ctx.db.player().entity_id().find(&new.entity_id) else {
+        return;
+    };
+    println!("PLAYER UPDATED New x-Pos {}", new.position.x);
     unsafe {
-        update_player_pos(new_player.player_id, new_player.position.clone());
+        update_player_pos(player.player_id, new.position.clone());
     }
 }
 
@@ -140,7 +144,7 @@ fn register_callbacks(ctx: &DbConnection) {
     // When a new user joins, print a notification.
     ctx.db.player().on_insert(on_player_inserted);
 
-    ctx.db.player().on_update(on_player_update);
+    ctx.db.transform().on_update(on_transform_update);
 
     // // When a user's status changes, print a notification.
     // ctx.db.user().on_update(on_user_updated);
@@ -169,7 +173,7 @@ fn subscribe_to_tables(ctx: &DbConnection) {
     ctx.subscription_builder()
         .on_applied(on_sub_applied)
         .on_error(on_sub_error)
-        .subscribe(["SELECT * FROM player"]);
+        .subscribe(["SELECT * FROM player", "SELECT * FROM transform"]);
 }
 
 
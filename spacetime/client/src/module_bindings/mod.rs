@@ -6,37 +6,447 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+pub mod accept_trade_reducer;
+pub mod add_crafting_station_reducer;
+pub mod add_patrol_waypoint_reducer;
+pub mod add_recipe_reducer;
+pub mod add_resource_node_reducer;
+pub mod add_spawn_point_reducer;
+pub mod add_static_collider_reducer;
+pub mod admin_role_type;
+pub mod admin_table;
+pub mod admin_type;
+pub mod advance_match_reducer;
+pub mod attack_command_type;
+pub mod attack_cooldown_table;
+pub mod attack_cooldown_type;
+pub mod attack_kind_type;
+pub mod ban_player_reducer;
+pub mod ban_table;
+pub mod ban_type;
+pub mod break_block_reducer;
+pub mod cancel_trade_reducer;
+pub mod chat_channel_type;
+pub mod chat_message_table;
+pub mod chat_message_type;
+pub mod chat_rate_limit_table;
+pub mod chat_rate_limit_type;
+pub mod chunk_table;
+pub mod chunk_type;
+pub mod collider_table;
+pub mod collider_type;
 pub mod command_type;
+pub mod craft_job_table;
+pub mod craft_job_type;
+pub mod craft_reducer;
+pub mod crafting_station_table;
+pub mod crafting_station_type;
+pub mod create_room_reducer;
+pub mod damage_source_type;
 pub mod db_vector_3_type;
+pub mod dead_table;
+pub mod dead_type;
+pub mod deal_damage_reducer;
+pub mod define_item_reducer;
+pub mod despawn_npc_reducer;
+pub mod drop_item_reducer;
+pub mod entity_kind_type;
+pub mod entity_table;
+pub mod entity_type;
+pub mod finish_craft_reducer;
+pub mod fire_command_type;
+pub mod gather_command_type;
+pub mod gather_cooldown_table;
+pub mod gather_cooldown_type;
+pub mod give_item_reducer;
+pub mod grant_admin_reducer;
+pub mod grant_currency_reducer;
+pub mod health_table;
+pub mod health_type;
 pub mod identity_connected_reducer;
 pub mod identity_disconnected_reducer;
+pub mod inventory_slot_table;
+pub mod inventory_slot_type;
+pub mod item_definition_table;
+pub mod item_definition_type;
+pub mod item_drop_table;
+pub mod item_drop_type;
+pub mod item_kind_type;
+pub mod item_stack_type;
+pub mod join_room_reducer;
+pub mod join_team_reducer;
+pub mod kick_player_reducer;
+pub mod leaderboard_entry_type;
+pub mod leaderboard_table;
+pub mod leaderboard_timer_table;
+pub mod leaderboard_timer_type;
+pub mod leave_room_reducer;
+pub mod ledger_entry_table;
+pub mod ledger_entry_type;
+pub mod ledger_reason_type;
+pub mod match_phase_type;
+pub mod match_score_table;
+pub mod match_score_type;
+pub mod match_state_table;
+pub mod match_state_type;
+pub mod match_timer_table;
+pub mod match_timer_type;
+pub mod moderation_action_type;
+pub mod moderation_log_table;
+pub mod moderation_log_type;
 pub mod move_all_players_reducer;
 pub mod move_all_players_timer_table;
 pub mod move_all_players_timer_type;
 pub mod move_command_type;
+pub mod move_item_reducer;
+pub mod mute_player_reducer;
+pub mod mute_table;
+pub mod mute_type;
+pub mod name_blocklist_table;
+pub mod name_blocklist_type;
+pub mod nav_blocked_cell_table;
+pub mod nav_blocked_cell_type;
+pub mod nav_path_table;
+pub mod nav_path_type;
+pub mod node_respawn_timer_table;
+pub mod node_respawn_timer_type;
+pub mod npc_behavior_type;
+pub mod npc_state_type;
+pub mod npc_table;
+pub mod npc_type;
+pub mod owner_table;
+pub mod owner_type;
+pub mod patrol_waypoint_table;
+pub mod patrol_waypoint_type;
+pub mod pending_item_table;
+pub mod pending_item_type;
+pub mod place_block_reducer;
 pub mod player_command_reducer;
+pub mod player_profile_table;
+pub mod player_profile_type;
+pub mod player_stats_table;
+pub mod player_stats_type;
 pub mod player_table;
 pub mod player_type;
-
+pub mod projectile_table;
+pub mod projectile_type;
+pub mod propose_trade_reducer;
+pub mod proximity_message_table;
+pub mod proximity_message_type;
+pub mod recipe_table;
+pub mod recipe_type;
+pub mod refresh_leaderboard_reducer;
+pub mod remove_crafting_station_reducer;
+pub mod remove_recipe_reducer;
+pub mod remove_resource_node_reducer;
+pub mod remove_spawn_point_reducer;
+pub mod remove_static_collider_reducer;
+pub mod resource_kind_type;
+pub mod resource_node_table;
+pub mod resource_node_type;
+pub mod respawn_node_reducer;
+pub mod respawn_reducer;
+pub mod respawn_timer_table;
+pub mod respawn_timer_type;
+pub mod revoke_admin_reducer;
+pub mod room_table;
+pub mod room_type;
+pub mod saved_death_type;
+pub mod send_message_reducer;
+pub mod set_day_length_reducer;
+pub mod set_friendly_fire_reducer;
+pub mod set_match_rules_reducer;
+pub mod set_max_rewind_reducer;
+pub mod set_name_reducer;
+pub mod set_respawn_delay_reducer;
+pub mod set_spawn_point_enabled_reducer;
+pub mod set_spawn_strategy_reducer;
+pub mod set_terrain_seed_reducer;
+pub mod set_time_of_day_reducer;
+pub mod skip_match_phase_reducer;
+pub mod spawn_npc_reducer;
+pub mod spawn_point_table;
+pub mod spawn_point_type;
+pub mod spawn_strategy_type;
+pub mod split_item_reducer;
+pub mod static_collider_table;
+pub mod static_collider_type;
+pub mod station_kind_type;
+pub mod team_member_table;
+pub mod team_member_type;
+pub mod team_table;
+pub mod team_type;
+pub mod teleport_player_reducer;
+pub mod trade_table;
+pub mod trade_type;
+pub mod transfer_reducer;
+pub mod transform_history_table;
+pub mod transform_history_type;
+pub mod transform_table;
+pub mod transform_type;
+pub mod unban_player_reducer;
+pub mod unmute_player_reducer;
+pub mod update_offer_reducer;
+pub mod use_item_reducer;
+pub mod velocity_table;
+pub mod velocity_type;
+pub mod wallet_table;
+pub mod wallet_type;
+pub mod world_clock_table;
+pub mod world_clock_type;
+pub mod world_config_table;
+pub mod world_config_type;
+
+pub use accept_trade_reducer::{accept_trade, set_flags_for_accept_trade, AcceptTradeCallbackId};
+pub use add_crafting_station_reducer::{
+    add_crafting_station, set_flags_for_add_crafting_station, AddCraftingStationCallbackId,
+};
+pub use add_patrol_waypoint_reducer::{
+    add_patrol_waypoint, set_flags_for_add_patrol_waypoint, AddPatrolWaypointCallbackId,
+};
+pub use add_recipe_reducer::{add_recipe, set_flags_for_add_recipe, AddRecipeCallbackId};
+pub use add_resource_node_reducer::{
+    add_resource_node, set_flags_for_add_resource_node, AddResourceNodeCallbackId,
+};
+pub use add_spawn_point_reducer::{
+    add_spawn_point, set_flags_for_add_spawn_point, AddSpawnPointCallbackId,
+};
+pub use add_static_collider_reducer::{
+    add_static_collider, set_flags_for_add_static_collider, AddStaticColliderCallbackId,
+};
+pub use admin_role_type::AdminRole;
+pub use admin_table::*;
+pub use admin_type::Admin;
+pub use advance_match_reducer::{
+    advance_match, set_flags_for_advance_match, AdvanceMatchCallbackId,
+};
+pub use attack_command_type::AttackCommand;
+pub use attack_cooldown_table::*;
+pub use attack_cooldown_type::AttackCooldown;
+pub use attack_kind_type::AttackKind;
+pub use ban_player_reducer::{ban_player, set_flags_for_ban_player, BanPlayerCallbackId};
+pub use ban_table::*;
+pub use ban_type::Ban;
+pub use break_block_reducer::{break_block, set_flags_for_break_block, BreakBlockCallbackId};
+pub use cancel_trade_reducer::{cancel_trade, set_flags_for_cancel_trade, CancelTradeCallbackId};
+pub use chat_channel_type::ChatChannel;
+pub use chat_message_table::*;
+pub use chat_message_type::ChatMessage;
+pub use chat_rate_limit_table::*;
+pub use chat_rate_limit_type::ChatRateLimit;
+pub use chunk_table::*;
+pub use chunk_type::Chunk;
+pub use collider_table::*;
+pub use collider_type::Collider;
 pub use command_type::Command;
+pub use craft_job_table::*;
+pub use craft_job_type::CraftJob;
+pub use craft_reducer::{craft, set_flags_for_craft, CraftCallbackId};
+pub use crafting_station_table::*;
+pub use crafting_station_type::CraftingStation;
+pub use create_room_reducer::{create_room, set_flags_for_create_room, CreateRoomCallbackId};
+pub use damage_source_type::DamageSource;
 pub use db_vector_3_type::DbVector3;
+pub use dead_table::*;
+pub use dead_type::Dead;
+pub use deal_damage_reducer::{deal_damage, set_flags_for_deal_damage, DealDamageCallbackId};
+pub use define_item_reducer::{define_item, set_flags_for_define_item, DefineItemCallbackId};
+pub use despawn_npc_reducer::{despawn_npc, set_flags_for_despawn_npc, DespawnNpcCallbackId};
+pub use drop_item_reducer::{drop_item, set_flags_for_drop_item, DropItemCallbackId};
+pub use entity_kind_type::EntityKind;
+pub use entity_table::*;
+pub use entity_type::Entity;
+pub use finish_craft_reducer::{finish_craft, set_flags_for_finish_craft, FinishCraftCallbackId};
+pub use fire_command_type::FireCommand;
+pub use gather_command_type::GatherCommand;
+pub use gather_cooldown_table::*;
+pub use gather_cooldown_type::GatherCooldown;
+pub use give_item_reducer::{give_item, set_flags_for_give_item, GiveItemCallbackId};
+pub use grant_admin_reducer::{grant_admin, set_flags_for_grant_admin, GrantAdminCallbackId};
+pub use grant_currency_reducer::{
+    grant_currency, set_flags_for_grant_currency, GrantCurrencyCallbackId,
+};
+pub use health_table::*;
+pub use health_type::Health;
 pub use identity_connected_reducer::{
     identity_connected, set_flags_for_identity_connected, IdentityConnectedCallbackId,
 };
 pub use identity_disconnected_reducer::{
     identity_disconnected, set_flags_for_identity_disconnected, IdentityDisconnectedCallbackId,
 };
+pub use inventory_slot_table::*;
+pub use inventory_slot_type::InventorySlot;
+pub use item_definition_table::*;
+pub use item_definition_type::ItemDefinition;
+pub use item_drop_table::*;
+pub use item_drop_type::ItemDrop;
+pub use item_kind_type::ItemKind;
+pub use item_stack_type::ItemStack;
+pub use join_room_reducer::{join_room, set_flags_for_join_room, JoinRoomCallbackId};
+pub use join_team_reducer::{join_team, set_flags_for_join_team, JoinTeamCallbackId};
+pub use kick_player_reducer::{kick_player, set_flags_for_kick_player, KickPlayerCallbackId};
+pub use leaderboard_entry_type::LeaderboardEntry;
+pub use leaderboard_table::*;
+pub use leaderboard_timer_table::*;
+pub use leaderboard_timer_type::LeaderboardTimer;
+pub use leave_room_reducer::{leave_room, set_flags_for_leave_room, LeaveRoomCallbackId};
+pub use ledger_entry_table::*;
+pub use ledger_entry_type::LedgerEntry;
+pub use ledger_reason_type::LedgerReason;
+pub use match_phase_type::MatchPhase;
+pub use match_score_table::*;
+pub use match_score_type::MatchScore;
+pub use match_state_table::*;
+pub use match_state_type::MatchState;
+pub use match_timer_table::*;
+pub use match_timer_type::MatchTimer;
+pub use moderation_action_type::ModerationAction;
+pub use moderation_log_table::*;
+pub use moderation_log_type::ModerationLog;
 pub use move_all_players_reducer::{
     move_all_players, set_flags_for_move_all_players, MoveAllPlayersCallbackId,
 };
 pub use move_all_players_timer_table::*;
 pub use move_all_players_timer_type::MoveAllPlayersTimer;
 pub use move_command_type::MoveCommand;
+pub use move_item_reducer::{move_item, set_flags_for_move_item, MoveItemCallbackId};
+pub use mute_player_reducer::{mute_player, set_flags_for_mute_player, MutePlayerCallbackId};
+pub use mute_table::*;
+pub use mute_type::Mute;
+pub use name_blocklist_table::*;
+pub use name_blocklist_type::NameBlocklist;
+pub use nav_blocked_cell_table::*;
+pub use nav_blocked_cell_type::NavBlockedCell;
+pub use nav_path_table::*;
+pub use nav_path_type::NavPath;
+pub use node_respawn_timer_table::*;
+pub use node_respawn_timer_type::NodeRespawnTimer;
+pub use npc_behavior_type::NpcBehavior;
+pub use npc_state_type::NpcState;
+pub use npc_table::*;
+pub use npc_type::Npc;
+pub use owner_table::*;
+pub use owner_type::Owner;
+pub use patrol_waypoint_table::*;
+pub use patrol_waypoint_type::PatrolWaypoint;
+pub use pending_item_table::*;
+pub use pending_item_type::PendingItem;
+pub use place_block_reducer::{place_block, set_flags_for_place_block, PlaceBlockCallbackId};
 pub use player_command_reducer::{
     player_command, set_flags_for_player_command, PlayerCommandCallbackId,
 };
+pub use player_profile_table::*;
+pub use player_profile_type::PlayerProfile;
+pub use player_stats_table::*;
+pub use player_stats_type::PlayerStats;
 pub use player_table::*;
 pub use player_type::Player;
+pub use projectile_table::*;
+pub use projectile_type::Projectile;
+pub use propose_trade_reducer::{
+    propose_trade, set_flags_for_propose_trade, ProposeTradeCallbackId,
+};
+pub use proximity_message_table::*;
+pub use proximity_message_type::ProximityMessage;
+pub use recipe_table::*;
+pub use recipe_type::Recipe;
+pub use refresh_leaderboard_reducer::{
+    refresh_leaderboard, set_flags_for_refresh_leaderboard, RefreshLeaderboardCallbackId,
+};
+pub use remove_crafting_station_reducer::{
+    remove_crafting_station, set_flags_for_remove_crafting_station, RemoveCraftingStationCallbackId,
+};
+pub use remove_recipe_reducer::{
+    remove_recipe, set_flags_for_remove_recipe, RemoveRecipeCallbackId,
+};
+pub use remove_resource_node_reducer::{
+    remove_resource_node, set_flags_for_remove_resource_node, RemoveResourceNodeCallbackId,
+};
+pub use remove_spawn_point_reducer::{
+    remove_spawn_point, set_flags_for_remove_spawn_point, RemoveSpawnPointCallbackId,
+};
+pub use remove_static_collider_reducer::{
+    remove_static_collider, set_flags_for_remove_static_collider, RemoveStaticColliderCallbackId,
+};
+pub use resource_kind_type::ResourceKind;
+pub use resource_node_table::*;
+pub use resource_node_type::ResourceNode;
+pub use respawn_node_reducer::{respawn_node, set_flags_for_respawn_node, RespawnNodeCallbackId};
+pub use respawn_reducer::{respawn, set_flags_for_respawn, RespawnCallbackId};
+pub use respawn_timer_table::*;
+pub use respawn_timer_type::RespawnTimer;
+pub use revoke_admin_reducer::{revoke_admin, set_flags_for_revoke_admin, RevokeAdminCallbackId};
+pub use room_table::*;
+pub use room_type::Room;
+pub use saved_death_type::SavedDeath;
+pub use send_message_reducer::{send_message, set_flags_for_send_message, SendMessageCallbackId};
+pub use set_day_length_reducer::{
+    set_day_length, set_flags_for_set_day_length, SetDayLengthCallbackId,
+};
+pub use set_friendly_fire_reducer::{
+    set_flags_for_set_friendly_fire, set_friendly_fire, SetFriendlyFireCallbackId,
+};
+pub use set_match_rules_reducer::{
+    set_flags_for_set_match_rules, set_match_rules, SetMatchRulesCallbackId,
+};
+pub use set_max_rewind_reducer::{
+    set_flags_for_set_max_rewind, set_max_rewind, SetMaxRewindCallbackId,
+};
+pub use set_name_reducer::{set_flags_for_set_name, set_name, SetNameCallbackId};
+pub use set_respawn_delay_reducer::{
+    set_flags_for_set_respawn_delay, set_respawn_delay, SetRespawnDelayCallbackId,
+};
+pub use set_spawn_point_enabled_reducer::{
+    set_flags_for_set_spawn_point_enabled, set_spawn_point_enabled, SetSpawnPointEnabledCallbackId,
+};
+pub use set_spawn_strategy_reducer::{
+    set_flags_for_set_spawn_strategy, set_spawn_strategy, SetSpawnStrategyCallbackId,
+};
+pub use set_terrain_seed_reducer::{
+    set_flags_for_set_terrain_seed, set_terrain_seed, SetTerrainSeedCallbackId,
+};
+pub use set_time_of_day_reducer::{
+    set_flags_for_set_time_of_day, set_time_of_day, SetTimeOfDayCallbackId,
+};
+pub use skip_match_phase_reducer::{
+    set_flags_for_skip_match_phase, skip_match_phase, SkipMatchPhaseCallbackId,
+};
+pub use spawn_npc_reducer::{set_flags_for_spawn_npc, spawn_npc, SpawnNpcCallbackId};
+pub use spawn_point_table::*;
+pub use spawn_point_type::SpawnPoint;
+pub use spawn_strategy_type::SpawnStrategy;
+pub use split_item_reducer::{set_flags_for_split_item, split_item, SplitItemCallbackId};
+pub use static_collider_table::*;
+pub use static_collider_type::StaticCollider;
+pub use station_kind_type::StationKind;
+pub use team_member_table::*;
+pub use team_member_type::TeamMember;
+pub use team_table::*;
+pub use team_type::Team;
+pub use teleport_player_reducer::{
+    set_flags_for_teleport_player, teleport_player, TeleportPlayerCallbackId,
+};
+pub use trade_table::*;
+pub use trade_type::Trade;
+pub use transfer_reducer::{set_flags_for_transfer, transfer, TransferCallbackId};
+pub use transform_history_table::*;
+pub use transform_history_type::TransformHistory;
+pub use transform_table::*;
+pub use transform_type::Transform;
+pub use unban_player_reducer::{set_flags_for_unban_player, unban_player, UnbanPlayerCallbackId};
+pub use unmute_player_reducer::{
+    set_flags_for_unmute_player, unmute_player, UnmutePlayerCallbackId,
+};
+pub use update_offer_reducer::{set_flags_for_update_offer, update_offer, UpdateOfferCallbackId};
+pub use use_item_reducer::{set_flags_for_use_item, use_item, UseItemCallbackId};
+pub use velocity_table::*;
+pub use velocity_type::Velocity;
+pub use wallet_table::*;
+pub use wallet_type::Wallet;
+pub use world_clock_table::*;
+pub use world_clock_type::WorldClock;
+pub use world_config_table::*;
+pub use world_config_type::WorldConfig;
 
 #[derive(Clone, PartialEq, Debug)]
 
@@ -46,10 +456,229 @@ pub use player_type::Player;
 /// to indicate which reducer caused the event.
 
 pub enum Reducer {
+    AcceptTrade {
+        trade_id: u32,
+    },
+    AddCraftingStation {
+        kind: StationKind,
+        position: DbVector3,
+    },
+    AddPatrolWaypoint {
+        entity_id: u32,
+        position: DbVector3,
+    },
+    AddRecipe {
+        name: String,
+        inputs: Vec<ItemStack>,
+        outputs: Vec<ItemStack>,
+        craft_time_ms: u32,
+        station: Option<StationKind>,
+    },
+    AddResourceNode {
+        kind: ResourceKind,
+        position: DbVector3,
+    },
+    AddSpawnPoint {
+        position: DbVector3,
+        rotation: DbVector3,
+        team: Option<u32>,
+    },
+    AddStaticCollider {
+        center: DbVector3,
+        half_extents: DbVector3,
+    },
+    AdvanceMatch {
+        timer: MatchTimer,
+    },
+    BanPlayer {
+        target: __sdk::Identity,
+        reason: String,
+        duration_secs: Option<u64>,
+    },
+    BreakBlock {
+        x: i32,
+        y: i32,
+        z: i32,
+    },
+    CancelTrade {
+        trade_id: u32,
+    },
+    Craft {
+        recipe_id: u32,
+    },
+    CreateRoom {
+        name: String,
+        max_players: u32,
+    },
+    DealDamage {
+        entity_id: u32,
+        amount: f32,
+    },
+    DefineItem {
+        name: String,
+        max_stack: u32,
+        kind: ItemKind,
+    },
+    DespawnNpc {
+        entity_id: u32,
+    },
+    DropItem {
+        slot: u32,
+        quantity: u32,
+    },
+    FinishCraft {
+        job: CraftJob,
+    },
+    GiveItem {
+        identity: __sdk::Identity,
+        item_id: u32,
+        quantity: u32,
+    },
+    GrantAdmin {
+        target: __sdk::Identity,
+        role: AdminRole,
+    },
+    GrantCurrency {
+        identity: __sdk::Identity,
+        amount: i64,
+    },
     IdentityConnected,
     IdentityDisconnected,
-    MoveAllPlayers { timer: MoveAllPlayersTimer },
-    PlayerCommand { cmd: Command },
+    JoinRoom {
+        room_id: u32,
+    },
+    JoinTeam {
+        team_id: Option<u32>,
+    },
+    KickPlayer {
+        target: __sdk::Identity,
+        reason: String,
+    },
+    LeaveRoom,
+    MoveAllPlayers {
+        timer: MoveAllPlayersTimer,
+    },
+    MoveItem {
+        from: u32,
+        to: u32,
+    },
+    MutePlayer {
+        target: __sdk::Identity,
+        reason: String,
+        duration_secs: Option<u64>,
+    },
+    PlaceBlock {
+        x: i32,
+        y: i32,
+        z: i32,
+        block: u8,
+    },
+    PlayerCommand {
+        cmd: Command,
+    },
+    ProposeTrade {
+        partner: __sdk::Identity,
+    },
+    RefreshLeaderboard {
+        timer: LeaderboardTimer,
+    },
+    RemoveCraftingStation {
+        station_id: u32,
+    },
+    RemoveRecipe {
+        recipe_id: u32,
+    },
+    RemoveResourceNode {
+        node_id: u32,
+    },
+    RemoveSpawnPoint {
+        spawn_id: u32,
+    },
+    RemoveStaticCollider {
+        collider_id: u32,
+    },
+    Respawn {
+        timer: RespawnTimer,
+    },
+    RespawnNode {
+        timer: NodeRespawnTimer,
+    },
+    RevokeAdmin {
+        target: __sdk::Identity,
+    },
+    SendMessage {
+        channel: ChatChannel,
+        text: String,
+    },
+    SetDayLength {
+        day_length_secs: u32,
+    },
+    SetFriendlyFire {
+        enabled: bool,
+    },
+    SetMatchRules {
+        warmup_secs: u32,
+        match_secs: u32,
+        score_limit: u32,
+    },
+    SetMaxRewind {
+        rewind_ms: u64,
+    },
+    SetName {
+        name: String,
+    },
+    SetRespawnDelay {
+        delay_ms: u64,
+    },
+    SetSpawnPointEnabled {
+        spawn_id: u32,
+        enabled: bool,
+    },
+    SetSpawnStrategy {
+        strategy: SpawnStrategy,
+    },
+    SetTerrainSeed {
+        seed: u64,
+    },
+    SetTimeOfDay {
+        time_of_day: f32,
+    },
+    SkipMatchPhase {
+        room_id: u32,
+    },
+    SpawnNpc {
+        room_id: u32,
+        name: String,
+        behavior: NpcBehavior,
+        position: DbVector3,
+    },
+    SplitItem {
+        from: u32,
+        to: u32,
+        quantity: u32,
+    },
+    TeleportPlayer {
+        target: __sdk::Identity,
+        position: DbVector3,
+    },
+    Transfer {
+        to: __sdk::Identity,
+        amount: u64,
+    },
+    UnbanPlayer {
+        target: __sdk::Identity,
+    },
+    UnmutePlayer {
+        target: __sdk::Identity,
+    },
+    UpdateOffer {
+        trade_id: u32,
+        offer: Vec<ItemStack>,
+        coins: u64,
+    },
+    UseItem {
+        slot: u32,
+    },
 }
 
 impl __sdk::InModule for Reducer {
@@ -59,10 +688,68 @@ impl __sdk::InModule for Reducer {
 impl __sdk::Reducer for Reducer {
     fn reducer_name(&self) -> &'static str {
         match self {
+            Reducer::AcceptTrade { .. } => "accept_trade",
+            Reducer::AddCraftingStation { .. } => "add_crafting_station",
+            Reducer::AddPatrolWaypoint { .. } => "add_patrol_waypoint",
+            Reducer::AddRecipe { .. } => "add_recipe",
+            Reducer::AddResourceNode { .. } => "add_resource_node",
+            Reducer::AddSpawnPoint { .. } => "add_spawn_point",
+            Reducer::AddStaticCollider { .. } => "add_static_collider",
+            Reducer::AdvanceMatch { .. } => "advance_match",
+            Reducer::BanPlayer { .. } => "ban_player",
+            Reducer::BreakBlock { .. } => "break_block",
+            Reducer::CancelTrade { .. } => "cancel_trade",
+            Reducer::Craft { .. } => "craft",
+            Reducer::CreateRoom { .. } => "create_room",
+            Reducer::DealDamage { .. } => "deal_damage",
+            Reducer::DefineItem { .. } => "define_item",
+            Reducer::DespawnNpc { .. } => "despawn_npc",
+            Reducer::DropItem { .. } => "drop_item",
+            Reducer::FinishCraft { .. } => "finish_craft",
+            Reducer::GiveItem { .. } => "give_item",
+            Reducer::GrantAdmin { .. } => "grant_admin",
+            Reducer::GrantCurrency { .. } => "grant_currency",
             Reducer::IdentityConnected => "identity_connected",
             Reducer::IdentityDisconnected => "identity_disconnected",
+            Reducer::JoinRoom { .. } => "join_room",
+            Reducer::JoinTeam { .. } => "join_team",
+            Reducer::KickPlayer { .. } => "kick_player",
+            Reducer::LeaveRoom => "leave_room",
             Reducer::MoveAllPlayers { .. } => "move_all_players",
+            Reducer::MoveItem { .. } => "move_item",
+            Reducer::MutePlayer { .. } => "mute_player",
+            Reducer::PlaceBlock { .. } => "place_block",
             Reducer::PlayerCommand { .. } => "player_command",
+            Reducer::ProposeTrade { .. } => "propose_trade",
+            Reducer::RefreshLeaderboard { .. } => "refresh_leaderboard",
+            Reducer::RemoveCraftingStation { .. } => "remove_crafting_station",
+            Reducer::RemoveRecipe { .. } => "remove_recipe",
+            Reducer::RemoveResourceNode { .. } => "remove_resource_node",
+            Reducer::RemoveSpawnPoint { .. } => "remove_spawn_point",
+            Reducer::RemoveStaticCollider { .. } => "remove_static_collider",
+            Reducer::Respawn { .. } => "respawn",
+            Reducer::RespawnNode { .. } => "respawn_node",
+            Reducer::RevokeAdmin { .. } => "revoke_admin",
+            Reducer::SendMessage { .. } => "send_message",
+            Reducer::SetDayLength { .. } => "set_day_length",
+            Reducer::SetFriendlyFire { .. } => "set_friendly_fire",
+            Reducer::SetMatchRules { .. } => "set_match_rules",
+            Reducer::SetMaxRewind { .. } => "set_max_rewind",
+            Reducer::SetName { .. } => "set_name",
+            Reducer::SetRespawnDelay { .. } => "set_respawn_delay",
+            Reducer::SetSpawnPointEnabled { .. } => "set_spawn_point_enabled",
+            Reducer::SetSpawnStrategy { .. } => "set_spawn_strategy",
+            Reducer::SetTerrainSeed { .. } => "set_terrain_seed",
+            Reducer::SetTimeOfDay { .. } => "set_time_of_day",
+            Reducer::SkipMatchPhase { .. } => "skip_match_phase",
+            Reducer::SpawnNpc { .. } => "spawn_npc",
+            Reducer::SplitItem { .. } => "split_item",
+            Reducer::TeleportPlayer { .. } => "teleport_player",
+            Reducer::Transfer { .. } => "transfer",
+            Reducer::UnbanPlayer { .. } => "unban_player",
+            Reducer::UnmutePlayer { .. } => "unmute_player",
+            Reducer::UpdateOffer { .. } => "update_offer",
+            Reducer::UseItem { .. } => "use_item",
         }
     }
 }
@@ -70,6 +757,130 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
     type Error = __sdk::Error;
     fn try_from(value: __ws::ReducerCallInfo<__ws::BsatnFormat>) -> __sdk::Result<Self> {
         match &value.reducer_name[..] {
+            "accept_trade" => Ok(
+                __sdk::parse_reducer_args::<accept_trade_reducer::AcceptTradeArgs>(
+                    "accept_trade",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "add_crafting_station" => Ok(__sdk::parse_reducer_args::<
+                add_crafting_station_reducer::AddCraftingStationArgs,
+            >("add_crafting_station", &value.args)?
+            .into()),
+            "add_patrol_waypoint" => Ok(__sdk::parse_reducer_args::<
+                add_patrol_waypoint_reducer::AddPatrolWaypointArgs,
+            >("add_patrol_waypoint", &value.args)?
+            .into()),
+            "add_recipe" => Ok(
+                __sdk::parse_reducer_args::<add_recipe_reducer::AddRecipeArgs>(
+                    "add_recipe",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "add_resource_node" => Ok(__sdk::parse_reducer_args::<
+                add_resource_node_reducer::AddResourceNodeArgs,
+            >("add_resource_node", &value.args)?
+            .into()),
+            "add_spawn_point" => Ok(__sdk::parse_reducer_args::<
+                add_spawn_point_reducer::AddSpawnPointArgs,
+            >("add_spawn_point", &value.args)?
+            .into()),
+            "add_static_collider" => Ok(__sdk::parse_reducer_args::<
+                add_static_collider_reducer::AddStaticColliderArgs,
+            >("add_static_collider", &value.args)?
+            .into()),
+            "advance_match" => Ok(__sdk::parse_reducer_args::<
+                advance_match_reducer::AdvanceMatchArgs,
+            >("advance_match", &value.args)?
+            .into()),
+            "ban_player" => Ok(
+                __sdk::parse_reducer_args::<ban_player_reducer::BanPlayerArgs>(
+                    "ban_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "break_block" => Ok(
+                __sdk::parse_reducer_args::<break_block_reducer::BreakBlockArgs>(
+                    "break_block",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "cancel_trade" => Ok(
+                __sdk::parse_reducer_args::<cancel_trade_reducer::CancelTradeArgs>(
+                    "cancel_trade",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "craft" => Ok(__sdk::parse_reducer_args::<craft_reducer::CraftArgs>(
+                "craft",
+                &value.args,
+            )?
+            .into()),
+            "create_room" => Ok(
+                __sdk::parse_reducer_args::<create_room_reducer::CreateRoomArgs>(
+                    "create_room",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "deal_damage" => Ok(
+                __sdk::parse_reducer_args::<deal_damage_reducer::DealDamageArgs>(
+                    "deal_damage",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "define_item" => Ok(
+                __sdk::parse_reducer_args::<define_item_reducer::DefineItemArgs>(
+                    "define_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "despawn_npc" => Ok(
+                __sdk::parse_reducer_args::<despawn_npc_reducer::DespawnNpcArgs>(
+                    "despawn_npc",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "drop_item" => Ok(
+                __sdk::parse_reducer_args::<drop_item_reducer::DropItemArgs>(
+                    "drop_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "finish_craft" => Ok(
+                __sdk::parse_reducer_args::<finish_craft_reducer::FinishCraftArgs>(
+                    "finish_craft",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "give_item" => Ok(
+                __sdk::parse_reducer_args::<give_item_reducer::GiveItemArgs>(
+                    "give_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "grant_admin" => Ok(
+                __sdk::parse_reducer_args::<grant_admin_reducer::GrantAdminArgs>(
+                    "grant_admin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "grant_currency" => Ok(__sdk::parse_reducer_args::<
+                grant_currency_reducer::GrantCurrencyArgs,
+            >("grant_currency", &value.args)?
+            .into()),
             "identity_connected" => Ok(__sdk::parse_reducer_args::<
                 identity_connected_reducer::IdentityConnectedArgs,
             >("identity_connected", &value.args)?
@@ -78,14 +889,208 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
                 identity_disconnected_reducer::IdentityDisconnectedArgs,
             >("identity_disconnected", &value.args)?
             .into()),
+            "join_room" => Ok(
+                __sdk::parse_reducer_args::<join_room_reducer::JoinRoomArgs>(
+                    "join_room",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "join_team" => Ok(
+                __sdk::parse_reducer_args::<join_team_reducer::JoinTeamArgs>(
+                    "join_team",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "kick_player" => Ok(
+                __sdk::parse_reducer_args::<kick_player_reducer::KickPlayerArgs>(
+                    "kick_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "leave_room" => Ok(
+                __sdk::parse_reducer_args::<leave_room_reducer::LeaveRoomArgs>(
+                    "leave_room",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "move_all_players" => Ok(__sdk::parse_reducer_args::<
                 move_all_players_reducer::MoveAllPlayersArgs,
             >("move_all_players", &value.args)?
             .into()),
+            "move_item" => Ok(
+                __sdk::parse_reducer_args::<move_item_reducer::MoveItemArgs>(
+                    "move_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "mute_player" => Ok(
+                __sdk::parse_reducer_args::<mute_player_reducer::MutePlayerArgs>(
+                    "mute_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "place_block" => Ok(
+                __sdk::parse_reducer_args::<place_block_reducer::PlaceBlockArgs>(
+                    "place_block",
+                    &value.args,
+                )?
+                .into(),
+            ),
             "player_command" => Ok(__sdk::parse_reducer_args::<
                 player_command_reducer::PlayerCommandArgs,
             >("player_command", &value.args)?
             .into()),
+            "propose_trade" => Ok(__sdk::parse_reducer_args::<
+                propose_trade_reducer::ProposeTradeArgs,
+            >("propose_trade", &value.args)?
+            .into()),
+            "refresh_leaderboard" => Ok(__sdk::parse_reducer_args::<
+                refresh_leaderboard_reducer::RefreshLeaderboardArgs,
+            >("refresh_leaderboard", &value.args)?
+            .into()),
+            "remove_crafting_station" => Ok(__sdk::parse_reducer_args::<
+                remove_crafting_station_reducer::RemoveCraftingStationArgs,
+            >("remove_crafting_station", &value.args)?
+            .into()),
+            "remove_recipe" => Ok(__sdk::parse_reducer_args::<
+                remove_recipe_reducer::RemoveRecipeArgs,
+            >("remove_recipe", &value.args)?
+            .into()),
+            "remove_resource_node" => Ok(__sdk::parse_reducer_args::<
+                remove_resource_node_reducer::RemoveResourceNodeArgs,
+            >("remove_resource_node", &value.args)?
+            .into()),
+            "remove_spawn_point" => Ok(__sdk::parse_reducer_args::<
+                remove_spawn_point_reducer::RemoveSpawnPointArgs,
+            >("remove_spawn_point", &value.args)?
+            .into()),
+            "remove_static_collider" => Ok(__sdk::parse_reducer_args::<
+                remove_static_collider_reducer::RemoveStaticColliderArgs,
+            >("remove_static_collider", &value.args)?
+            .into()),
+            "respawn" => Ok(__sdk::parse_reducer_args::<respawn_reducer::RespawnArgs>(
+                "respawn",
+                &value.args,
+            )?
+            .into()),
+            "respawn_node" => Ok(
+                __sdk::parse_reducer_args::<respawn_node_reducer::RespawnNodeArgs>(
+                    "respawn_node",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "revoke_admin" => Ok(
+                __sdk::parse_reducer_args::<revoke_admin_reducer::RevokeAdminArgs>(
+                    "revoke_admin",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "send_message" => Ok(
+                __sdk::parse_reducer_args::<send_message_reducer::SendMessageArgs>(
+                    "send_message",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "set_day_length" => Ok(__sdk::parse_reducer_args::<
+                set_day_length_reducer::SetDayLengthArgs,
+            >("set_day_length", &value.args)?
+            .into()),
+            "set_friendly_fire" => Ok(__sdk::parse_reducer_args::<
+                set_friendly_fire_reducer::SetFriendlyFireArgs,
+            >("set_friendly_fire", &value.args)?
+            .into()),
+            "set_match_rules" => Ok(__sdk::parse_reducer_args::<
+                set_match_rules_reducer::SetMatchRulesArgs,
+            >("set_match_rules", &value.args)?
+            .into()),
+            "set_max_rewind" => Ok(__sdk::parse_reducer_args::<
+                set_max_rewind_reducer::SetMaxRewindArgs,
+            >("set_max_rewind", &value.args)?
+            .into()),
+            "set_name" => Ok(__sdk::parse_reducer_args::<set_name_reducer::SetNameArgs>(
+                "set_name",
+                &value.args,
+            )?
+            .into()),
+            "set_respawn_delay" => Ok(__sdk::parse_reducer_args::<
+                set_respawn_delay_reducer::SetRespawnDelayArgs,
+            >("set_respawn_delay", &value.args)?
+            .into()),
+            "set_spawn_point_enabled" => Ok(__sdk::parse_reducer_args::<
+                set_spawn_point_enabled_reducer::SetSpawnPointEnabledArgs,
+            >("set_spawn_point_enabled", &value.args)?
+            .into()),
+            "set_spawn_strategy" => Ok(__sdk::parse_reducer_args::<
+                set_spawn_strategy_reducer::SetSpawnStrategyArgs,
+            >("set_spawn_strategy", &value.args)?
+            .into()),
+            "set_terrain_seed" => Ok(__sdk::parse_reducer_args::<
+                set_terrain_seed_reducer::SetTerrainSeedArgs,
+            >("set_terrain_seed", &value.args)?
+            .into()),
+            "set_time_of_day" => Ok(__sdk::parse_reducer_args::<
+                set_time_of_day_reducer::SetTimeOfDayArgs,
+            >("set_time_of_day", &value.args)?
+            .into()),
+            "skip_match_phase" => Ok(__sdk::parse_reducer_args::<
+                skip_match_phase_reducer::SkipMatchPhaseArgs,
+            >("skip_match_phase", &value.args)?
+            .into()),
+            "spawn_npc" => Ok(
+                __sdk::parse_reducer_args::<spawn_npc_reducer::SpawnNpcArgs>(
+                    "spawn_npc",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "split_item" => Ok(
+                __sdk::parse_reducer_args::<split_item_reducer::SplitItemArgs>(
+                    "split_item",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "teleport_player" => Ok(__sdk::parse_reducer_args::<
+                teleport_player_reducer::TeleportPlayerArgs,
+            >("teleport_player", &value.args)?
+            .into()),
+            "transfer" => Ok(__sdk::parse_reducer_args::<transfer_reducer::TransferArgs>(
+                "transfer",
+                &value.args,
+            )?
+            .into()),
+            "unban_player" => Ok(
+                __sdk::parse_reducer_args::<unban_player_reducer::UnbanPlayerArgs>(
+                    "unban_player",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "unmute_player" => Ok(__sdk::parse_reducer_args::<
+                unmute_player_reducer::UnmutePlayerArgs,
+            >("unmute_player", &value.args)?
+            .into()),
+            "update_offer" => Ok(
+                __sdk::parse_reducer_args::<update_offer_reducer::UpdateOfferArgs>(
+                    "update_offer",
+                    &value.args,
+                )?
+                .into(),
+            ),
+            "use_item" => Ok(__sdk::parse_reducer_args::<use_item_reducer::UseItemArgs>(
+                "use_item",
+                &value.args,
+            )?
+            .into()),
             unknown => {
                 Err(
                     __sdk::InternalError::unknown_name("reducer", unknown, "ReducerCallInfo")
@@ -100,8 +1105,59 @@ impl TryFrom<__ws::ReducerCallInfo<__ws::BsatnFormat>> for Reducer {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct DbUpdate {
+    admin: __sdk::TableUpdate<Admin>,
+    attack_cooldown: __sdk::TableUpdate<AttackCooldown>,
+    ban: __sdk::TableUpdate<Ban>,
+    chat_message: __sdk::TableUpdate<ChatMessage>,
+    chat_rate_limit: __sdk::TableUpdate<ChatRateLimit>,
+    chunk: __sdk::TableUpdate<Chunk>,
+    collider: __sdk::TableUpdate<Collider>,
+    craft_job: __sdk::TableUpdate<CraftJob>,
+    crafting_station: __sdk::TableUpdate<CraftingStation>,
+    dead: __sdk::TableUpdate<Dead>,
+    entity: __sdk::TableUpdate<Entity>,
+    gather_cooldown: __sdk::TableUpdate<GatherCooldown>,
+    health: __sdk::TableUpdate<Health>,
+    inventory_slot: __sdk::TableUpdate<InventorySlot>,
+    item_definition: __sdk::TableUpdate<ItemDefinition>,
+    item_drop: __sdk::TableUpdate<ItemDrop>,
+    leaderboard: __sdk::TableUpdate<LeaderboardEntry>,
+    leaderboard_timer: __sdk::TableUpdate<LeaderboardTimer>,
+    ledger_entry: __sdk::TableUpdate<LedgerEntry>,
+    match_score: __sdk::TableUpdate<MatchScore>,
+    match_state: __sdk::TableUpdate<MatchState>,
+    match_timer: __sdk::TableUpdate<MatchTimer>,
+    moderation_log: __sdk::TableUpdate<ModerationLog>,
     move_all_players_timer: __sdk::TableUpdate<MoveAllPlayersTimer>,
+    mute: __sdk::TableUpdate<Mute>,
+    name_blocklist: __sdk::TableUpdate<NameBlocklist>,
+    nav_blocked_cell: __sdk::TableUpdate<NavBlockedCell>,
+    nav_path: __sdk::TableUpdate<NavPath>,
+    node_respawn_timer: __sdk::TableUpdate<NodeRespawnTimer>,
+    npc: __sdk::TableUpdate<Npc>,
+    owner: __sdk::TableUpdate<Owner>,
+    patrol_waypoint: __sdk::TableUpdate<PatrolWaypoint>,
+    pending_item: __sdk::TableUpdate<PendingItem>,
     player: __sdk::TableUpdate<Player>,
+    player_profile: __sdk::TableUpdate<PlayerProfile>,
+    player_stats: __sdk::TableUpdate<PlayerStats>,
+    projectile: __sdk::TableUpdate<Projectile>,
+    proximity_message: __sdk::TableUpdate<ProximityMessage>,
+    recipe: __sdk::TableUpdate<Recipe>,
+    resource_node: __sdk::TableUpdate<ResourceNode>,
+    respawn_timer: __sdk::TableUpdate<RespawnTimer>,
+    room: __sdk::TableUpdate<Room>,
+    spawn_point: __sdk::TableUpdate<SpawnPoint>,
+    static_collider: __sdk::TableUpdate<StaticCollider>,
+    team: __sdk::TableUpdate<Team>,
+    team_member: __sdk::TableUpdate<TeamMember>,
+    trade: __sdk::TableUpdate<Trade>,
+    transform: __sdk::TableUpdate<Transform>,
+    transform_history: __sdk::TableUpdate<TransformHistory>,
+    velocity: __sdk::TableUpdate<Velocity>,
+    wallet: __sdk::TableUpdate<Wallet>,
+    world_clock: __sdk::TableUpdate<WorldClock>,
+    world_config: __sdk::TableUpdate<WorldConfig>,
 }
 
 impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
@@ -110,12 +1166,165 @@ impl TryFrom<__ws::DatabaseUpdate<__ws::BsatnFormat>> for DbUpdate {
         let mut db_update = DbUpdate::default();
         for table_update in raw.tables {
             match &table_update.table_name[..] {
+                "admin" => db_update
+                    .admin
+                    .append(admin_table::parse_table_update(table_update)?),
+                "attack_cooldown" => db_update
+                    .attack_cooldown
+                    .append(attack_cooldown_table::parse_table_update(table_update)?),
+                "ban" => db_update
+                    .ban
+                    .append(ban_table::parse_table_update(table_update)?),
+                "chat_message" => db_update
+                    .chat_message
+                    .append(chat_message_table::parse_table_update(table_update)?),
+                "chat_rate_limit" => db_update
+                    .chat_rate_limit
+                    .append(chat_rate_limit_table::parse_table_update(table_update)?),
+                "chunk" => db_update
+                    .chunk
+                    .append(chunk_table::parse_table_update(table_update)?),
+                "collider" => db_update
+                    .collider
+                    .append(collider_table::parse_table_update(table_update)?),
+                "craft_job" => db_update
+                    .craft_job
+                    .append(craft_job_table::parse_table_update(table_update)?),
+                "crafting_station" => db_update
+                    .crafting_station
+                    .append(crafting_station_table::parse_table_update(table_update)?),
+                "dead" => db_update
+                    .dead
+                    .append(dead_table::parse_table_update(table_update)?),
+                "entity" => db_update
+                    .entity
+                    .append(entity_table::parse_table_update(table_update)?),
+                "gather_cooldown" => db_update
+                    .gather_cooldown
+                    .append(gather_cooldown_table::parse_table_update(table_update)?),
+                "health" => db_update
+                    .health
+                    .append(health_table::parse_table_update(table_update)?),
+                "inventory_slot" => db_update
+                    .inventory_slot
+                    .append(inventory_slot_table::parse_table_update(table_update)?),
+                "item_definition" => db_update
+                    .item_definition
+                    .append(item_definition_table::parse_table_update(table_update)?),
+                "item_drop" => db_update
+                    .item_drop
+                    .append(item_drop_table::parse_table_update(table_update)?),
+                "leaderboard" => db_update
+                    .leaderboard
+                    .append(leaderboard_table::parse_table_update(table_update)?),
+                "leaderboard_timer" => db_update
+                    .leaderboard_timer
+                    .append(leaderboard_timer_table::parse_table_update(table_update)?),
+                "ledger_entry" => db_update
+                    .ledger_entry
+                    .append(ledger_entry_table::parse_table_update(table_update)?),
+                "match_score" => db_update
+                    .match_score
+                    .append(match_score_table::parse_table_update(table_update)?),
+                "match_state" => db_update
+                    .match_state
+                    .append(match_state_table::parse_table_update(table_update)?),
+                "match_timer" => db_update
+                    .match_timer
+                    .append(match_timer_table::parse_table_update(table_update)?),
+                "moderation_log" => db_update
+                    .moderation_log
+                    .append(moderation_log_table::parse_table_update(table_update)?),
                 "move_all_players_timer" => db_update.move_all_players_timer.append(
                     move_all_players_timer_table::parse_table_update(table_update)?,
                 ),
+                "mute" => db_update
+                    .mute
+                    .append(mute_table::parse_table_update(table_update)?),
+                "name_blocklist" => db_update
+                    .name_blocklist
+                    .append(name_blocklist_table::parse_table_update(table_update)?),
+                "nav_blocked_cell" => db_update
+                    .nav_blocked_cell
+                    .append(nav_blocked_cell_table::parse_table_update(table_update)?),
+                "nav_path" => db_update
+                    .nav_path
+                    .append(nav_path_table::parse_table_update(table_update)?),
+                "node_respawn_timer" => db_update
+                    .node_respawn_timer
+                    .append(node_respawn_timer_table::parse_table_update(table_update)?),
+                "npc" => db_update
+                    .npc
+                    .append(npc_table::parse_table_update(table_update)?),
+                "owner" => db_update
+                    .owner
+                    .append(owner_table::parse_table_update(table_update)?),
+                "patrol_waypoint" => db_update
+                    .patrol_waypoint
+                    .append(patrol_waypoint_table::parse_table_update(table_update)?),
+                "pending_item" => db_update
+                    .pending_item
+                    .append(pending_item_table::parse_table_update(table_update)?),
                 "player" => db_update
                     .player
                     .append(player_table::parse_table_update(table_update)?),
+                "player_profile" => db_update
+                    .player_profile
+                    .append(player_profile_table::parse_table_update(table_update)?),
+                "player_stats" => db_update
+                    .player_stats
+                    .append(player_stats_table::parse_table_update(table_update)?),
+                "projectile" => db_update
+                    .projectile
+                    .append(projectile_table::parse_table_update(table_update)?),
+                "proximity_message" => db_update
+                    .proximity_message
+                    .append(proximity_message_table::parse_table_update(table_update)?),
+                "recipe" => db_update
+                    .recipe
+                    .append(recipe_table::parse_table_update(table_update)?),
+                "resource_node" => db_update
+                    .resource_node
+                    .append(resource_node_table::parse_table_update(table_update)?),
+                "respawn_timer" => db_update
+                    .respawn_timer
+                    .append(respawn_timer_table::parse_table_update(table_update)?),
+                "room" => db_update
+                    .room
+                    .append(room_table::parse_table_update(table_update)?),
+                "spawn_point" => db_update
+                    .spawn_point
+                    .append(spawn_point_table::parse_table_update(table_update)?),
+                "static_collider" => db_update
+                    .static_collider
+                    .append(static_collider_table::parse_table_update(table_update)?),
+                "team" => db_update
+                    .team
+                    .append(team_table::parse_table_update(table_update)?),
+                "team_member" => db_update
+                    .team_member
+                    .append(team_member_table::parse_table_update(table_update)?),
+                "trade" => db_update
+                    .trade
+                    .append(trade_table::parse_table_update(table_update)?),
+                "transform" => db_update
+                    .transform
+                    .append(transform_table::parse_table_update(table_update)?),
+                "transform_history" => db_update
+                    .transform_history
+                    .append(transform_history_table::parse_table_update(table_update)?),
+                "velocity" => db_update
+                    .velocity
+                    .append(velocity_table::parse_table_update(table_update)?),
+                "wallet" => db_update
+                    .wallet
+                    .append(wallet_table::parse_table_update(table_update)?),
+                "world_clock" => db_update
+                    .world_clock
+                    .append(world_clock_table::parse_table_update(table_update)?),
+                "world_config" => db_update
+                    .world_config
+                    .append(world_config_table::parse_table_update(table_update)?),
 
                 unknown => {
                     return Err(__sdk::InternalError::unknown_name(
@@ -142,15 +1351,168 @@ impl __sdk::DbUpdate for DbUpdate {
     ) -> AppliedDiff<'_> {
         let mut diff = AppliedDiff::default();
 
+        diff.admin = cache
+            .apply_diff_to_table::<Admin>("admin", &self.admin)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.attack_cooldown = cache
+            .apply_diff_to_table::<AttackCooldown>("attack_cooldown", &self.attack_cooldown)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.ban = cache
+            .apply_diff_to_table::<Ban>("ban", &self.ban)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.chat_message = cache
+            .apply_diff_to_table::<ChatMessage>("chat_message", &self.chat_message)
+            .with_updates_by_pk(|row| &row.message_id);
+        diff.chat_rate_limit = cache
+            .apply_diff_to_table::<ChatRateLimit>("chat_rate_limit", &self.chat_rate_limit)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.chunk = cache
+            .apply_diff_to_table::<Chunk>("chunk", &self.chunk)
+            .with_updates_by_pk(|row| &row.key);
+        diff.collider = cache
+            .apply_diff_to_table::<Collider>("collider", &self.collider)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.craft_job = cache
+            .apply_diff_to_table::<CraftJob>("craft_job", &self.craft_job)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.crafting_station = cache
+            .apply_diff_to_table::<CraftingStation>("crafting_station", &self.crafting_station)
+            .with_updates_by_pk(|row| &row.station_id);
+        diff.dead = cache
+            .apply_diff_to_table::<Dead>("dead", &self.dead)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.entity = cache
+            .apply_diff_to_table::<Entity>("entity", &self.entity)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.gather_cooldown = cache
+            .apply_diff_to_table::<GatherCooldown>("gather_cooldown", &self.gather_cooldown)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.health = cache
+            .apply_diff_to_table::<Health>("health", &self.health)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.inventory_slot = cache
+            .apply_diff_to_table::<InventorySlot>("inventory_slot", &self.inventory_slot)
+            .with_updates_by_pk(|row| &row.slot_id);
+        diff.item_definition = cache
+            .apply_diff_to_table::<ItemDefinition>("item_definition", &self.item_definition)
+            .with_updates_by_pk(|row| &row.item_id);
+        diff.item_drop = cache
+            .apply_diff_to_table::<ItemDrop>("item_drop", &self.item_drop)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.leaderboard = cache
+            .apply_diff_to_table::<LeaderboardEntry>("leaderboard", &self.leaderboard)
+            .with_updates_by_pk(|row| &row.rank);
+        diff.leaderboard_timer = cache
+            .apply_diff_to_table::<LeaderboardTimer>("leaderboard_timer", &self.leaderboard_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.ledger_entry = cache
+            .apply_diff_to_table::<LedgerEntry>("ledger_entry", &self.ledger_entry)
+            .with_updates_by_pk(|row| &row.entry_id);
+        diff.match_score = cache
+            .apply_diff_to_table::<MatchScore>("match_score", &self.match_score)
+            .with_updates_by_pk(|row| &row.score_id);
+        diff.match_state = cache
+            .apply_diff_to_table::<MatchState>("match_state", &self.match_state)
+            .with_updates_by_pk(|row| &row.room_id);
+        diff.match_timer = cache
+            .apply_diff_to_table::<MatchTimer>("match_timer", &self.match_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.moderation_log = cache
+            .apply_diff_to_table::<ModerationLog>("moderation_log", &self.moderation_log)
+            .with_updates_by_pk(|row| &row.log_id);
         diff.move_all_players_timer = cache
             .apply_diff_to_table::<MoveAllPlayersTimer>(
                 "move_all_players_timer",
                 &self.move_all_players_timer,
             )
             .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.mute = cache
+            .apply_diff_to_table::<Mute>("mute", &self.mute)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.name_blocklist = cache
+            .apply_diff_to_table::<NameBlocklist>("name_blocklist", &self.name_blocklist)
+            .with_updates_by_pk(|row| &row.word);
+        diff.nav_blocked_cell = cache
+            .apply_diff_to_table::<NavBlockedCell>("nav_blocked_cell", &self.nav_blocked_cell)
+            .with_updates_by_pk(|row| &row.cell_id);
+        diff.nav_path = cache
+            .apply_diff_to_table::<NavPath>("nav_path", &self.nav_path)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.node_respawn_timer = cache
+            .apply_diff_to_table::<NodeRespawnTimer>("node_respawn_timer", &self.node_respawn_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.npc = cache
+            .apply_diff_to_table::<Npc>("npc", &self.npc)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.owner = cache
+            .apply_diff_to_table::<Owner>("owner", &self.owner)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.patrol_waypoint = cache
+            .apply_diff_to_table::<PatrolWaypoint>("patrol_waypoint", &self.patrol_waypoint)
+            .with_updates_by_pk(|row| &row.waypoint_id);
+        diff.pending_item = cache
+            .apply_diff_to_table::<PendingItem>("pending_item", &self.pending_item)
+            .with_updates_by_pk(|row| &row.pending_id);
         diff.player = cache
             .apply_diff_to_table::<Player>("player", &self.player)
             .with_updates_by_pk(|row| &row.identity);
+        diff.player_profile = cache
+            .apply_diff_to_table::<PlayerProfile>("player_profile", &self.player_profile)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.player_stats = cache
+            .apply_diff_to_table::<PlayerStats>("player_stats", &self.player_stats)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.projectile = cache
+            .apply_diff_to_table::<Projectile>("projectile", &self.projectile)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.proximity_message = cache
+            .apply_diff_to_table::<ProximityMessage>("proximity_message", &self.proximity_message)
+            .with_updates_by_pk(|row| &row.message_id);
+        diff.recipe = cache
+            .apply_diff_to_table::<Recipe>("recipe", &self.recipe)
+            .with_updates_by_pk(|row| &row.recipe_id);
+        diff.resource_node = cache
+            .apply_diff_to_table::<ResourceNode>("resource_node", &self.resource_node)
+            .with_updates_by_pk(|row| &row.node_id);
+        diff.respawn_timer = cache
+            .apply_diff_to_table::<RespawnTimer>("respawn_timer", &self.respawn_timer)
+            .with_updates_by_pk(|row| &row.scheduled_id);
+        diff.room = cache
+            .apply_diff_to_table::<Room>("room", &self.room)
+            .with_updates_by_pk(|row| &row.room_id);
+        diff.spawn_point = cache
+            .apply_diff_to_table::<SpawnPoint>("spawn_point", &self.spawn_point)
+            .with_updates_by_pk(|row| &row.spawn_id);
+        diff.static_collider = cache
+            .apply_diff_to_table::<StaticCollider>("static_collider", &self.static_collider)
+            .with_updates_by_pk(|row| &row.collider_id);
+        diff.team = cache
+            .apply_diff_to_table::<Team>("team", &self.team)
+            .with_updates_by_pk(|row| &row.team_id);
+        diff.team_member = cache
+            .apply_diff_to_table::<TeamMember>("team_member", &self.team_member)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.trade = cache
+            .apply_diff_to_table::<Trade>("trade", &self.trade)
+            .with_updates_by_pk(|row| &row.trade_id);
+        diff.transform = cache
+            .apply_diff_to_table::<Transform>("transform", &self.transform)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.transform_history = cache
+            .apply_diff_to_table::<TransformHistory>("transform_history", &self.transform_history)
+            .with_updates_by_pk(|row| &row.history_id);
+        diff.velocity = cache
+            .apply_diff_to_table::<Velocity>("velocity", &self.velocity)
+            .with_updates_by_pk(|row| &row.entity_id);
+        diff.wallet = cache
+            .apply_diff_to_table::<Wallet>("wallet", &self.wallet)
+            .with_updates_by_pk(|row| &row.identity);
+        diff.world_clock = cache
+            .apply_diff_to_table::<WorldClock>("world_clock", &self.world_clock)
+            .with_updates_by_pk(|row| &row.id);
+        diff.world_config = cache
+            .apply_diff_to_table::<WorldConfig>("world_config", &self.world_config)
+            .with_updates_by_pk(|row| &row.id);
 
         diff
     }
@@ -160,8 +1522,59 @@ impl __sdk::DbUpdate for DbUpdate {
 #[allow(non_snake_case)]
 #[doc(hidden)]
 pub struct AppliedDiff<'r> {
+    admin: __sdk::TableAppliedDiff<'r, Admin>,
+    attack_cooldown: __sdk::TableAppliedDiff<'r, AttackCooldown>,
+    ban: __sdk::TableAppliedDiff<'r, Ban>,
+    chat_message: __sdk::TableAppliedDiff<'r, ChatMessage>,
+    chat_rate_limit: __sdk::TableAppliedDiff<'r, ChatRateLimit>,
+    chunk: __sdk::TableAppliedDiff<'r, Chunk>,
+    collider: __sdk::TableAppliedDiff<'r, Collider>,
+    craft_job: __sdk::TableAppliedDiff<'r, CraftJob>,
+    crafting_station: __sdk::TableAppliedDiff<'r, CraftingStation>,
+    dead: __sdk::TableAppliedDiff<'r, Dead>,
+    entity: __sdk::TableAppliedDiff<'r, Entity>,
+    gather_cooldown: __sdk::TableAppliedDiff<'r, GatherCooldown>,
+    health: __sdk::TableAppliedDiff<'r, Health>,
+    inventory_slot: __sdk::TableAppliedDiff<'r, InventorySlot>,
+    item_definition: __sdk::TableAppliedDiff<'r, ItemDefinition>,
+    item_drop: __sdk::TableAppliedDiff<'r, ItemDrop>,
+    leaderboard: __sdk::TableAppliedDiff<'r, LeaderboardEntry>,
+    leaderboard_timer: __sdk::TableAppliedDiff<'r, LeaderboardTimer>,
+    ledger_entry: __sdk::TableAppliedDiff<'r, LedgerEntry>,
+    match_score: __sdk::TableAppliedDiff<'r, MatchScore>,
+    match_state: __sdk::TableAppliedDiff<'r, MatchState>,
+    match_timer: __sdk::TableAppliedDiff<'r, MatchTimer>,
+    moderation_log: __sdk::TableAppliedDiff<'r, ModerationLog>,
     move_all_players_timer: __sdk::TableAppliedDiff<'r, MoveAllPlayersTimer>,
+    mute: __sdk::TableAppliedDiff<'r, Mute>,
+    name_blocklist: __sdk::TableAppliedDiff<'r, NameBlocklist>,
+    nav_blocked_cell: __sdk::TableAppliedDiff<'r, NavBlockedCell>,
+    nav_path: __sdk::TableAppliedDiff<'r, NavPath>,
+    node_respawn_timer: __sdk::TableAppliedDiff<'r, NodeRespawnTimer>,
+    npc: __sdk::TableAppliedDiff<'r, Npc>,
+    owner: __sdk::TableAppliedDiff<'r, Owner>,
+    patrol_waypoint: __sdk::TableAppliedDiff<'r, PatrolWaypoint>,
+    pending_item: __sdk::TableAppliedDiff<'r, PendingItem>,
     player: __sdk::TableAppliedDiff<'r, Player>,
+    player_profile: __sdk::TableAppliedDiff<'r, PlayerProfile>,
+    player_stats: __sdk::TableAppliedDiff<'r, PlayerStats>,
+    projectile: __sdk::TableAppliedDiff<'r, Projectile>,
+    proximity_message: __sdk::TableAppliedDiff<'r, ProximityMessage>,
+    recipe: __sdk::TableAppliedDiff<'r, Recipe>,
+    resource_node: __sdk::TableAppliedDiff<'r, ResourceNode>,
+    respawn_timer: __sdk::TableAppliedDiff<'r, RespawnTimer>,
+    room: __sdk::TableAppliedDiff<'r, Room>,
+    spawn_point: __sdk::TableAppliedDiff<'r, SpawnPoint>,
+    static_collider: __sdk::TableAppliedDiff<'r, StaticCollider>,
+    team: __sdk::TableAppliedDiff<'r, Team>,
+    team_member: __sdk::TableAppliedDiff<'r, TeamMember>,
+    trade: __sdk::TableAppliedDiff<'r, Trade>,
+    transform: __sdk::TableAppliedDiff<'r, Transform>,
+    transform_history: __sdk::TableAppliedDiff<'r, TransformHistory>,
+    velocity: __sdk::TableAppliedDiff<'r, Velocity>,
+    wallet: __sdk::TableAppliedDiff<'r, Wallet>,
+    world_clock: __sdk::TableAppliedDiff<'r, WorldClock>,
+    world_config: __sdk::TableAppliedDiff<'r, WorldConfig>,
 }
 
 impl __sdk::InModule for AppliedDiff<'_> {
@@ -174,12 +1587,159 @@ impl<'r> __sdk::AppliedDiff<'r> for AppliedDiff<'r> {
         event: &EventContext,
         callbacks: &mut __sdk::DbCallbacks<RemoteModule>,
     ) {
+        callbacks.invoke_table_row_callbacks::<Admin>("admin", &self.admin, event);
+        callbacks.invoke_table_row_callbacks::<AttackCooldown>(
+            "attack_cooldown",
+            &self.attack_cooldown,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Ban>("ban", &self.ban, event);
+        callbacks.invoke_table_row_callbacks::<ChatMessage>(
+            "chat_message",
+            &self.chat_message,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ChatRateLimit>(
+            "chat_rate_limit",
+            &self.chat_rate_limit,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Chunk>("chunk", &self.chunk, event);
+        callbacks.invoke_table_row_callbacks::<Collider>("collider", &self.collider, event);
+        callbacks.invoke_table_row_callbacks::<CraftJob>("craft_job", &self.craft_job, event);
+        callbacks.invoke_table_row_callbacks::<CraftingStation>(
+            "crafting_station",
+            &self.crafting_station,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Dead>("dead", &self.dead, event);
+        callbacks.invoke_table_row_callbacks::<Entity>("entity", &self.entity, event);
+        callbacks.invoke_table_row_callbacks::<GatherCooldown>(
+            "gather_cooldown",
+            &self.gather_cooldown,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Health>("health", &self.health, event);
+        callbacks.invoke_table_row_callbacks::<InventorySlot>(
+            "inventory_slot",
+            &self.inventory_slot,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ItemDefinition>(
+            "item_definition",
+            &self.item_definition,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<ItemDrop>("item_drop", &self.item_drop, event);
+        callbacks.invoke_table_row_callbacks::<LeaderboardEntry>(
+            "leaderboard",
+            &self.leaderboard,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LeaderboardTimer>(
+            "leaderboard_timer",
+            &self.leaderboard_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<LedgerEntry>(
+            "ledger_entry",
+            &self.ledger_entry,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<MatchScore>("match_score", &self.match_score, event);
+        callbacks.invoke_table_row_callbacks::<MatchState>("match_state", &self.match_state, event);
+        callbacks.invoke_table_row_callbacks::<MatchTimer>("match_timer", &self.match_timer, event);
+        callbacks.invoke_table_row_callbacks::<ModerationLog>(
+            "moderation_log",
+            &self.moderation_log,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<MoveAllPlayersTimer>(
             "move_all_players_timer",
             &self.move_all_players_timer,
             event,
         );
+        callbacks.invoke_table_row_callbacks::<Mute>("mute", &self.mute, event);
+        callbacks.invoke_table_row_callbacks::<NameBlocklist>(
+            "name_blocklist",
+            &self.name_blocklist,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<NavBlockedCell>(
+            "nav_blocked_cell",
+            &self.nav_blocked_cell,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<NavPath>("nav_path", &self.nav_path, event);
+        callbacks.invoke_table_row_callbacks::<NodeRespawnTimer>(
+            "node_respawn_timer",
+            &self.node_respawn_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Npc>("npc", &self.npc, event);
+        callbacks.invoke_table_row_callbacks::<Owner>("owner", &self.owner, event);
+        callbacks.invoke_table_row_callbacks::<PatrolWaypoint>(
+            "patrol_waypoint",
+            &self.patrol_waypoint,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PendingItem>(
+            "pending_item",
+            &self.pending_item,
+            event,
+        );
         callbacks.invoke_table_row_callbacks::<Player>("player", &self.player, event);
+        callbacks.invoke_table_row_callbacks::<PlayerProfile>(
+            "player_profile",
+            &self.player_profile,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<PlayerStats>(
+            "player_stats",
+            &self.player_stats,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Projectile>("projectile", &self.projectile, event);
+        callbacks.invoke_table_row_callbacks::<ProximityMessage>(
+            "proximity_message",
+            &self.proximity_message,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Recipe>("recipe", &self.recipe, event);
+        callbacks.invoke_table_row_callbacks::<ResourceNode>(
+            "resource_node",
+            &self.resource_node,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<RespawnTimer>(
+            "respawn_timer",
+            &self.respawn_timer,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Room>("room", &self.room, event);
+        callbacks.invoke_table_row_callbacks::<SpawnPoint>("spawn_point", &self.spawn_point, event);
+        callbacks.invoke_table_row_callbacks::<StaticCollider>(
+            "static_collider",
+            &self.static_collider,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Team>("team", &self.team, event);
+        callbacks.invoke_table_row_callbacks::<TeamMember>("team_member", &self.team_member, event);
+        callbacks.invoke_table_row_callbacks::<Trade>("trade", &self.trade, event);
+        callbacks.invoke_table_row_callbacks::<Transform>("transform", &self.transform, event);
+        callbacks.invoke_table_row_callbacks::<TransformHistory>(
+            "transform_history",
+            &self.transform_history,
+            event,
+        );
+        callbacks.invoke_table_row_callbacks::<Velocity>("velocity", &self.velocity, event);
+        callbacks.invoke_table_row_callbacks::<Wallet>("wallet", &self.wallet, event);
+        callbacks.invoke_table_row_callbacks::<WorldClock>("world_clock", &self.world_clock, event);
+        callbacks.invoke_table_row_callbacks::<WorldConfig>(
+            "world_config",
+            &self.world_config,
+            event,
+        );
     }
 }
 
@@ -770,7 +2330,58 @@ impl __sdk::SpacetimeModule for RemoteModule {
     type SubscriptionHandle = SubscriptionHandle;
 
     fn register_tables(client_cache: &mut __sdk::ClientCache<Self>) {
+        admin_table::register_table(client_cache);
+        attack_cooldown_table::register_table(client_cache);
+        ban_table::register_table(client_cache);
+        chat_message_table::register_table(client_cache);
+        chat_rate_limit_table::register_table(client_cache);
+        chunk_table::register_table(client_cache);
+        collider_table::register_table(client_cache);
+        craft_job_table::register_table(client_cache);
+        crafting_station_table::register_table(client_cache);
+        dead_table::register_table(client_cache);
+        entity_table::register_table(client_cache);
+        gather_cooldown_table::register_table(client_cache);
+        health_table::register_table(client_cache);
+        inventory_slot_table::register_table(client_cache);
+        item_definition_table::register_table(client_cache);
+        item_drop_table::register_table(client_cache);
+        leaderboard_table::register_table(client_cache);
+        leaderboard_timer_table::register_table(client_cache);
+        ledger_entry_table::register_table(client_cache);
+        match_score_table::register_table(client_cache);
+        match_state_table::register_table(client_cache);
+        match_timer_table::register_table(client_cache);
+        moderation_log_table::register_table(client_cache);
         move_all_players_timer_table::register_table(client_cache);
+        mute_table::register_table(client_cache);
+        name_blocklist_table::register_table(client_cache);
+        nav_blocked_cell_table::register_table(client_cache);
+        nav_path_table::register_table(client_cache);
+        node_respawn_timer_table::register_table(client_cache);
+        npc_table::register_table(client_cache);
+        owner_table::register_table(client_cache);
+        patrol_waypoint_table::register_table(client_cache);
+        pending_item_table::register_table(client_cache);
         player_table::register_table(client_cache);
+        player_profile_table::register_table(client_cache);
+        player_stats_table::register_table(client_cache);
+        projectile_table::register_table(client_cache);
+        proximity_message_table::register_table(client_cache);
+        recipe_table::register_table(client_cache);
+        resource_node_table::register_table(client_cache);
+        respawn_timer_table::register_table(client_cache);
+        room_table::register_table(client_cache);
+        spawn_point_table::register_table(client_cache);
+        static_collider_table::register_table(client_cache);
+        team_table::register_table(client_cache);
+        team_member_table::register_table(client_cache);
+        trade_table::register_table(client_cache);
+        transform_table::register_table(client_cache);
+        transform_history_table::register_table(client_cache);
+        velocity_table::register_table(client_cache);
+        wallet_table::register_table(client_cache);
+        world_clock_table::register_table(client_cache);
+        world_config_table::register_table(client_cache);
     }
 }
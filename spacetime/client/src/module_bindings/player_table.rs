@@ -83,6 +83,7 @@ impl<'ctx> __sdk::Table for PlayerTableHandle<'ctx> {
 pub(super) fn register_table(client_cache: &mut __sdk::ClientCache<super::RemoteModule>) {
     let _table = client_cache.get_or_make_table::<Player>("player");
     _table.add_unique_constraint::<__sdk::Identity>("identity", |row| &row.identity);
+    _table.add_unique_constraint::<u32>("entity_id", |row| &row.entity_id);
 }
 pub struct PlayerUpdateCallbackId(__sdk::CallbackId);
 
@@ -143,3 +144,33 @@ impl<'ctx> PlayerIdentityUnique<'ctx> {
         self.imp.find(col_val)
     }
 }
+
+/// Access to the `entity_id` unique index on the table `player`,
+/// which allows point queries on the field of the same name
+/// via the [`PlayerEntityIdUnique::find`] method.
+///
+/// Users are encouraged not to explicitly reference this type,
+/// but to directly chain method calls,
+/// like `ctx.db.player().entity_id().find(...)`.
+pub struct PlayerEntityIdUnique<'ctx> {
+    imp: __sdk::UniqueConstraintHandle<Player, u32>,
+    phantom: std::marker::PhantomData<&'ctx super::RemoteTables>,
+}
+
+impl<'ctx> PlayerTableHandle<'ctx> {
+    /// Get a handle on the `entity_id` unique index on the table `player`.
+    pub fn entity_id(&self) -> PlayerEntityIdUnique<'ctx> {
+        PlayerEntityIdUnique {
+            imp: self.imp.get_unique_constraint::<u32>("entity_id"),
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<'ctx> PlayerEntityIdUnique<'ctx> {
+    /// Find the subscribed row whose `entity_id` column value is equal to `col_val`,
+    /// if such a row is present in the client cache.
+    pub fn find(&self, col_val: &u32) -> Option<Player> {
+        self.imp.find(col_val)
+    }
+}
@@ -11,10 +11,12 @@ use super::db_vector_3_type::DbVector3;
 pub struct Player {
     pub identity: __sdk::Identity,
     pub player_id: u32,
+    pub entity_id: u32,
+    pub room_id: u32,
     pub name: String,
-    pub position: DbVector3,
-    pub rotation: DbVector3,
     pub direction: DbVector3,
+    pub joined_at: __sdk::Timestamp,
+    pub facing: DbVector3,
 }
 
 impl __sdk::InModule for Player {
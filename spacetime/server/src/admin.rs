@@ -0,0 +1,326 @@
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+use crate::ecs;
+use crate::error::{ReducerError, ReducerResult};
+use crate::math::DbVector3;
+use crate::player;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum AdminRole {
+    Owner,      // Can grant and revoke roles
+    Moderator,  // Can kick, ban, mute and teleport
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum ModerationAction {
+    GrantAdmin,
+    RevokeAdmin,
+    Kick,
+    Ban,
+    Unban,
+    Mute,
+    Unmute,
+    Teleport,
+}
+
+#[spacetimedb::table(name = admin)]
+pub struct Admin {
+    #[primary_key]
+    identity: Identity,
+    role: AdminRole,
+    granted_by: Identity,
+    granted_at: Timestamp,
+}
+
+#[spacetimedb::table(name = ban)]
+pub struct Ban {
+    #[primary_key]
+    identity: Identity,
+    reason: String,
+    banned_by: Identity,
+    banned_at: Timestamp,
+    // None bans forever
+    expires_at: Option<Timestamp>,
+}
+
+#[spacetimedb::table(name = mute)]
+pub struct Mute {
+    #[primary_key]
+    identity: Identity,
+    reason: String,
+    muted_by: Identity,
+    muted_at: Timestamp,
+    // None mutes forever
+    expires_at: Option<Timestamp>,
+}
+
+// Every moderation action ends up here, newest has the highest id
+#[spacetimedb::table(name = moderation_log)]
+pub struct ModerationLog {
+    #[primary_key]
+    #[auto_inc]
+    log_id: u64,
+    actor: Identity,
+    target: Identity,
+    action: ModerationAction,
+    reason: String,
+    at: Timestamp,
+}
+
+// The identity publishing the module owns it
+pub fn seed_owner(ctx: &ReducerContext) {
+    if ctx.db.admin().identity().find(ctx.sender).is_none() {
+        log::info!("Seeding owner {}", ctx.sender);
+        ctx.db.admin().insert(Admin {
+            identity: ctx.sender,
+            role: AdminRole::Owner,
+            granted_by: ctx.sender,
+            granted_at: ctx.timestamp,
+        });
+    }
+}
+
+fn require_role(ctx: &ReducerContext, owner_only: bool) -> ReducerResult {
+    match ctx.db.admin().identity().find(ctx.sender) {
+        Some(admin) if admin.role == AdminRole::Owner || !owner_only => Ok(()),
+        _ => Err(ReducerError::PermissionDenied("Not an admin".to_string())),
+    }
+}
+
+// For reducers in other modules that only moderators and owners may call
+pub fn require_admin(ctx: &ReducerContext) -> ReducerResult {
+    require_role(ctx, false)
+}
+
+// Players without a role rank lowest
+fn rank(role: Option<AdminRole>) -> u8 {
+    match role {
+        None => 0,
+        Some(AdminRole::Moderator) => 1,
+        Some(AdminRole::Owner) => 2,
+    }
+}
+
+fn outranks(actor: Option<AdminRole>, target: Option<AdminRole>) -> bool {
+    rank(actor) > rank(target)
+}
+
+// Moderation only works downwards, nobody can act on their own rank or above
+fn require_outranks(ctx: &ReducerContext, target: Identity) -> ReducerResult {
+    let role_of = |identity| ctx.db.admin().identity().find(identity).map(|a| a.role);
+    if !outranks(role_of(ctx.sender), role_of(target)) {
+        return Err(ReducerError::PermissionDenied("Target's role is not below yours".to_string()));
+    }
+    Ok(())
+}
+
+fn expires_at(ctx: &ReducerContext, duration_secs: Option<u64>) -> Result<Option<Timestamp>, ReducerError> {
+    let Some(secs) = duration_secs else {
+        return Ok(None);
+    };
+    i64::try_from(secs)
+        .ok()
+        .and_then(|secs| secs.checked_mul(1_000_000))
+        .and_then(|micros| ctx.timestamp.to_micros_since_unix_epoch().checked_add(micros))
+        .map(|micros| Some(Timestamp::from_micros_since_unix_epoch(micros)))
+        .ok_or_else(|| ReducerError::InvalidInput("Duration is too long, leave it out for a permanent one".to_string()))
+}
+
+fn has_expired(ctx: &ReducerContext, expires_at: Option<Timestamp>) -> bool {
+    expires_at.is_some_and(|at| at <= ctx.timestamp)
+}
+
+fn log_action(ctx: &ReducerContext, target: Identity, action: ModerationAction, reason: String) {
+    log::info!("{} {:?} {}: {}", ctx.sender, action, target, reason);
+    ctx.db.moderation_log().insert(ModerationLog {
+        log_id: 0,
+        actor: ctx.sender,
+        target,
+        action,
+        reason,
+        at: ctx.timestamp,
+    });
+}
+
+// Remove the target's live player row, saving their profile first.
+// A module cannot close a client connection, but without a row every command fails until they reconnect.
+fn remove_player(ctx: &ReducerContext, target: Identity) -> bool {
+    match ctx.db.player().identity().find(target) {
+        Some(player) => {
+            crate::remove_player(ctx, player);
+            true
+        }
+        None => false,
+    }
+}
+
+// Called on connect, drops expired bans on the way
+pub fn check_ban(ctx: &ReducerContext) -> ReducerResult {
+    let Some(ban) = ctx.db.ban().identity().find(ctx.sender) else {
+        return Ok(());
+    };
+    if has_expired(ctx, ban.expires_at) {
+        ctx.db.ban().delete(ban);
+        return Ok(());
+    }
+    Err(ReducerError::PermissionDenied(format!("Banned: {}", ban.reason)))
+}
+
+// Called before sending chat, drops expired mutes on the way
+pub fn check_mute(ctx: &ReducerContext) -> ReducerResult {
+    let Some(mute) = ctx.db.mute().identity().find(ctx.sender) else {
+        return Ok(());
+    };
+    if has_expired(ctx, mute.expires_at) {
+        ctx.db.mute().delete(mute);
+        return Ok(());
+    }
+    Err(ReducerError::PermissionDenied(format!("Muted: {}", mute.reason)))
+}
+
+#[spacetimedb::reducer]
+pub fn grant_admin(ctx: &ReducerContext, target: Identity, role: AdminRole) -> ReducerResult {
+    require_role(ctx, true)?;
+    require_outranks(ctx, target)?;
+    let admin = Admin {
+        identity: target,
+        role,
+        granted_by: ctx.sender,
+        granted_at: ctx.timestamp,
+    };
+    if ctx.db.admin().identity().find(target).is_some() {
+        ctx.db.admin().identity().update(admin);
+    } else {
+        ctx.db.admin().insert(admin);
+    }
+    log_action(ctx, target, ModerationAction::GrantAdmin, format!("{:?}", role));
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn revoke_admin(ctx: &ReducerContext, target: Identity) -> ReducerResult {
+    require_role(ctx, true)?;
+    if target == ctx.sender {
+        return Err(ReducerError::InvalidInput("Cannot revoke your own role".to_string()));
+    }
+    require_outranks(ctx, target)?;
+    if !ctx.db.admin().identity().delete(target) {
+        return Err(ReducerError::NotFound("Not an admin".to_string()));
+    }
+    log_action(ctx, target, ModerationAction::RevokeAdmin, String::new());
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn kick_player(ctx: &ReducerContext, target: Identity, reason: String) -> ReducerResult {
+    require_role(ctx, false)?;
+    require_outranks(ctx, target)?;
+    if !remove_player(ctx, target) {
+        return Err(ReducerError::NotFound("Player is not online".to_string()));
+    }
+    log_action(ctx, target, ModerationAction::Kick, reason);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn ban_player(ctx: &ReducerContext, target: Identity, reason: String, duration_secs: Option<u64>) -> ReducerResult {
+    require_role(ctx, false)?;
+    if target == ctx.sender {
+        return Err(ReducerError::InvalidInput("Cannot ban yourself".to_string()));
+    }
+    require_outranks(ctx, target)?;
+    let expires_at = expires_at(ctx, duration_secs)?;
+    let ban = Ban {
+        identity: target,
+        reason: reason.clone(),
+        banned_by: ctx.sender,
+        banned_at: ctx.timestamp,
+        expires_at,
+    };
+    if ctx.db.ban().identity().find(target).is_some() {
+        ctx.db.ban().identity().update(ban);
+    } else {
+        ctx.db.ban().insert(ban);
+    }
+    remove_player(ctx, target);
+    log_action(ctx, target, ModerationAction::Ban, reason);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unban_player(ctx: &ReducerContext, target: Identity) -> ReducerResult {
+    require_role(ctx, false)?;
+    if !ctx.db.ban().identity().delete(target) {
+        return Err(ReducerError::NotFound("Player is not banned".to_string()));
+    }
+    log_action(ctx, target, ModerationAction::Unban, String::new());
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn mute_player(ctx: &ReducerContext, target: Identity, reason: String, duration_secs: Option<u64>) -> ReducerResult {
+    require_role(ctx, false)?;
+    require_outranks(ctx, target)?;
+    let expires_at = expires_at(ctx, duration_secs)?;
+    let mute = Mute {
+        identity: target,
+        reason: reason.clone(),
+        muted_by: ctx.sender,
+        muted_at: ctx.timestamp,
+        expires_at,
+    };
+    if ctx.db.mute().identity().find(target).is_some() {
+        ctx.db.mute().identity().update(mute);
+    } else {
+        ctx.db.mute().insert(mute);
+    }
+    log_action(ctx, target, ModerationAction::Mute, reason);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn unmute_player(ctx: &ReducerContext, target: Identity) -> ReducerResult {
+    require_role(ctx, false)?;
+    if !ctx.db.mute().identity().delete(target) {
+        return Err(ReducerError::NotFound("Player is not muted".to_string()));
+    }
+    log_action(ctx, target, ModerationAction::Unmute, String::new());
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn teleport_player(ctx: &ReducerContext, target: Identity, position: DbVector3) -> ReducerResult {
+    require_role(ctx, false)?;
+    require_outranks(ctx, target)?;
+    let player = ctx
+        .db
+        .player()
+        .identity()
+        .find(target)
+        .ok_or_else(|| ReducerError::NotFound("Player is not online".to_string()))?;
+    ecs::set_position(ctx, player.entity_id, position);
+    log_action(
+        ctx,
+        target,
+        ModerationAction::Teleport,
+        format!("({}, {}, {})", position.x, position.y, position.z),
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn moderation_only_works_downwards() {
+        let (owner, moderator) = (Some(AdminRole::Owner), Some(AdminRole::Moderator));
+        assert!(outranks(owner, moderator));
+        assert!(outranks(owner, None));
+        assert!(outranks(moderator, None));
+        assert!(!outranks(moderator, owner));
+        assert!(!outranks(moderator, moderator));
+        assert!(!outranks(owner, owner));
+        assert!(!outranks(None, None));
+    }
+}
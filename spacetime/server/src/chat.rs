@@ -0,0 +1,173 @@
+use std::time::Duration;
+
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+use crate::admin;
+use crate::ecs;
+use crate::error::{ReducerError, ReducerResult};
+use crate::math::DbVector3;
+use crate::player;
+use crate::Player;
+
+pub const MAX_MESSAGE_LEN: usize = 256;
+pub const PROXIMITY_RADIUS: f32 = 20.0;
+// Only the newest messages of each room (or proximity recipient) are kept around for late joiners
+const MAX_HISTORY: u64 = 100;
+// At most RATE_LIMIT_COUNT messages per RATE_LIMIT_WINDOW
+const RATE_LIMIT_COUNT: u32 = 5;
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(10);
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum ChatChannel {
+    Global,     // Everyone in the room, see `chat_message`
+    Proximity,  // Players within PROXIMITY_RADIUS of the sender, see `proximity_message`
+}
+
+// Global chat of a room
+#[spacetimedb::table(name = chat_message, public)]
+pub struct ChatMessage {
+    #[primary_key]
+    #[auto_inc]
+    message_id: u64,
+    // Only players in the same room see the message
+    #[index(btree)]
+    room_id: u32,
+    sender: Identity,
+    sender_name: String,
+    text: String,
+    sent_at: Timestamp,
+}
+
+// Proximity chat, one row for every player that was in range when it was sent
+#[spacetimedb::table(name = proximity_message, public)]
+pub struct ProximityMessage {
+    #[primary_key]
+    #[auto_inc]
+    message_id: u64,
+    #[index(btree)]
+    room_id: u32,
+    #[index(btree)]
+    recipient: Identity,
+    sender: Identity,
+    sender_name: String,
+    text: String,
+    sent_at: Timestamp,
+}
+
+// Clients only ever get the proximity messages addressed to them
+#[spacetimedb::client_visibility_filter]
+const PROXIMITY_RECIPIENT: spacetimedb::Filter =
+    spacetimedb::Filter::Sql("SELECT * FROM proximity_message WHERE recipient = :sender");
+
+#[spacetimedb::table(name = chat_rate_limit)]
+pub struct ChatRateLimit {
+    #[primary_key]
+    identity: Identity,
+    window_start: Timestamp,
+    count: u32,
+}
+
+fn check_rate_limit(ctx: &ReducerContext) -> ReducerResult {
+    let Some(mut limit) = ctx.db.chat_rate_limit().identity().find(ctx.sender) else {
+        ctx.db.chat_rate_limit().insert(ChatRateLimit {
+            identity: ctx.sender,
+            window_start: ctx.timestamp,
+            count: 1,
+        });
+        return Ok(());
+    };
+
+    let elapsed = ctx.timestamp.duration_since(limit.window_start).unwrap_or_default();
+    if elapsed >= RATE_LIMIT_WINDOW {
+        limit.window_start = ctx.timestamp;
+        limit.count = 0;
+    }
+    if limit.count >= RATE_LIMIT_COUNT {
+        return Err(ReducerError::RateLimited);
+    }
+    limit.count += 1;
+    ctx.db.chat_rate_limit().identity().update(limit);
+    Ok(())
+}
+
+// Reducer: Send a chat message from the calling player
+#[spacetimedb::reducer]
+pub fn send_message(ctx: &ReducerContext, channel: ChatChannel, text: String) -> ReducerResult {
+    let player = ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    let text = text.trim().to_string();
+    if text.is_empty() {
+        return Err(ReducerError::InvalidInput("Message is empty".to_string()));
+    }
+    if text.chars().count() > MAX_MESSAGE_LEN {
+        return Err(ReducerError::InvalidInput(format!(
+            "Message is longer than {} characters",
+            MAX_MESSAGE_LEN
+        )));
+    }
+    admin::check_mute(ctx)?;
+    check_rate_limit(ctx)?;
+
+    match channel {
+        ChatChannel::Global => send_global(ctx, player, text),
+        ChatChannel::Proximity => send_proximity(ctx, player, text),
+    }
+    Ok(())
+}
+
+// The message ids to delete so only the newest MAX_HISTORY of `ids` are left
+fn beyond_history(mut ids: Vec<u64>) -> Vec<u64> {
+    ids.sort_unstable();
+    ids.truncate(ids.len().saturating_sub(MAX_HISTORY as usize));
+    ids
+}
+
+fn send_global(ctx: &ReducerContext, player: Player, text: String) {
+    let room_id = player.room_id;
+    ctx.db.chat_message().insert(ChatMessage {
+        message_id: 0,
+        room_id,
+        sender: ctx.sender,
+        sender_name: player.name,
+        text,
+        sent_at: ctx.timestamp,
+    });
+
+    // Trim the room's history
+    let ids = ctx.db.chat_message().room_id().filter(room_id).map(|m| m.message_id).collect();
+    for message_id in beyond_history(ids) {
+        ctx.db.chat_message().message_id().delete(message_id);
+    }
+}
+
+// The range is checked here once, so players out of range never receive the text
+fn send_proximity(ctx: &ReducerContext, player: Player, text: String) {
+    let origin = ecs::position_of(ctx, player.entity_id).unwrap_or(DbVector3::ZERO);
+    let recipients: Vec<Identity> = ctx
+        .db
+        .player()
+        .room_id()
+        .filter(player.room_id)
+        .filter(|p| {
+            ecs::position_of(ctx, p.entity_id)
+                .is_some_and(|position| (position - origin).sqr_magnitude() <= PROXIMITY_RADIUS * PROXIMITY_RADIUS)
+        })
+        .map(|p| p.identity)
+        .collect();
+    for recipient in recipients {
+        ctx.db.proximity_message().insert(ProximityMessage {
+            message_id: 0,
+            room_id: player.room_id,
+            recipient,
+            sender: ctx.sender,
+            sender_name: player.name.clone(),
+            text: text.clone(),
+            sent_at: ctx.timestamp,
+        });
+
+        // Trim each recipient's history on its own
+        let ids = ctx.db.proximity_message().recipient().filter(recipient).map(|m| m.message_id).collect();
+        for message_id in beyond_history(ids) {
+            ctx.db.proximity_message().message_id().delete(message_id);
+        }
+    }
+}
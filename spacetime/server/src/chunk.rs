@@ -0,0 +1,155 @@
+use spacetimedb::{ReducerContext, Table, Timestamp};
+
+use crate::config;
+use crate::ecs;
+use crate::error::{ReducerError, ReducerResult};
+use crate::math::DbVector3;
+use crate::player;
+use crate::voxel::{self, BLOCK_AIR, BLOCK_KINDS};
+
+// Chunks around each player that are generated ahead of time, in chunks
+const PRELOAD_RADIUS: i32 = 2;
+// Spread generation over ticks so a new player does not stall the world
+const MAX_GENERATED_PER_TICK: usize = 4;
+// Players can only touch blocks this close to them
+const BLOCK_REACH: f32 = 6.0;
+// Distance between the points `segment_hits_block` tests, in blocks
+const SEGMENT_STEP: f32 = 0.5;
+
+// Block data of a room. Every room starts from the same terrain and keeps its own changes,
+// rows appear the first time anything needs the chunk.
+#[spacetimedb::table(name = chunk, public)]
+pub struct Chunk {
+    // See `voxel::chunk_key`
+    #[primary_key]
+    pub key: u128,
+    #[index(btree)]
+    pub room_id: u32,
+    #[index(btree)]
+    pub cx: i32,
+    pub cy: i32,
+    pub cz: i32,
+    // Run length encoded blocks, see `voxel::decompress`
+    pub data: Vec<u8>,
+    pub modified_at: Timestamp,
+}
+
+// Fetch a chunk, generating it from the terrain on first access
+pub fn ensure(ctx: &ReducerContext, room_id: u32, cx: i32, cy: i32, cz: i32) -> Chunk {
+    let key = voxel::chunk_key(room_id, cx, cy, cz);
+    if let Some(chunk) = ctx.db.chunk().key().find(key) {
+        return chunk;
+    }
+    let blocks = voxel::generate(config::get(ctx).terrain_seed, cx, cy, cz);
+    ctx.db.chunk().insert(Chunk {
+        key,
+        room_id,
+        cx,
+        cy,
+        cz,
+        data: voxel::compress(&blocks),
+        modified_at: ctx.timestamp,
+    })
+}
+
+pub fn block_at(ctx: &ReducerContext, room_id: u32, x: i32, y: i32, z: i32) -> u8 {
+    let (cx, cy, cz) = voxel::chunk_of(x, y, z);
+    voxel::decompress(&ensure(ctx, room_id, cx, cy, cz).data)[voxel::local_index(x, y, z)]
+}
+
+// Whether the straight path from `start` to `end` runs into a solid block. Checked at points
+// at most SEGMENT_STEP apart so fast movers do not skip over a block between ticks.
+pub fn segment_hits_block(ctx: &ReducerContext, room_id: u32, start: DbVector3, end: DbVector3) -> bool {
+    let steps = ((end - start).magnitude() / SEGMENT_STEP).ceil().max(1.0) as u32;
+    (1..=steps).any(|i| {
+        let point = start + (end - start) * (i as f32 / steps as f32);
+        block_at(ctx, room_id, point.x.floor() as i32, point.y.floor() as i32, point.z.floor() as i32) != BLOCK_AIR
+    })
+}
+
+// Top of the highest solid block in the column at (x, z), searching from `y` down to `y - depth`
+pub fn surface_below(ctx: &ReducerContext, room_id: u32, x: i32, y: i32, z: i32, depth: i32) -> Option<i32> {
+    // Blocks of the chunk the column is in at the moment, decompressed once per chunk
+    let mut loaded_at = None;
+    let mut blocks = Vec::new();
+    for by in (y - depth..=y).rev() {
+        let (cx, cy, cz) = voxel::chunk_of(x, by, z);
+        if loaded_at != Some((cx, cy, cz)) {
+            blocks = voxel::decompress(&ensure(ctx, room_id, cx, cy, cz).data);
+            loaded_at = Some((cx, cy, cz));
+        }
+        if blocks[voxel::local_index(x, by, z)] != BLOCK_AIR {
+            return Some(by + 1);
+        }
+    }
+    None
+}
+
+fn set_block(ctx: &ReducerContext, room_id: u32, x: i32, y: i32, z: i32, block: u8) {
+    let (cx, cy, cz) = voxel::chunk_of(x, y, z);
+    let mut chunk = ensure(ctx, room_id, cx, cy, cz);
+    let mut blocks = voxel::decompress(&chunk.data);
+    blocks[voxel::local_index(x, y, z)] = block;
+    chunk.data = voxel::compress(&blocks);
+    chunk.modified_at = ctx.timestamp;
+    ctx.db.chunk().key().update(chunk);
+}
+
+// System: generate the chunks around players of the room before they walk into them
+pub fn chunk_system(ctx: &ReducerContext, room_id: u32) {
+    let mut generated = 0;
+    for player in ctx.db.player().room_id().filter(room_id) {
+        let Some(position) = ecs::position_of(ctx, player.entity_id) else {
+            continue;
+        };
+        let (pcx, pcy, pcz) = voxel::chunk_of(position.x.floor() as i32, position.y.floor() as i32, position.z.floor() as i32);
+        for cz in pcz - PRELOAD_RADIUS..=pcz + PRELOAD_RADIUS {
+            for cx in pcx - PRELOAD_RADIUS..=pcx + PRELOAD_RADIUS {
+                // The ground under the player and what is right above it
+                for cy in pcy - 1..=pcy {
+                    if generated >= MAX_GENERATED_PER_TICK {
+                        return;
+                    }
+                    if ctx.db.chunk().key().find(voxel::chunk_key(room_id, cx, cy, cz)).is_none() {
+                        ensure(ctx, room_id, cx, cy, cz);
+                        generated += 1;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Returns the room of the sender, whose blocks they are allowed to touch
+fn check_reach(ctx: &ReducerContext, x: i32, y: i32, z: i32) -> Result<u32, ReducerError> {
+    let player = ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    let position = ecs::position_of(ctx, player.entity_id).ok_or(ReducerError::NotConnected)?;
+    let center = DbVector3::new(x as f32 + 0.5, y as f32 + 0.5, z as f32 + 0.5);
+    if (center - position).sqr_magnitude() > BLOCK_REACH * BLOCK_REACH {
+        return Err(ReducerError::InvalidInput("Block is out of reach".to_string()));
+    }
+    Ok(player.room_id)
+}
+
+#[spacetimedb::reducer]
+pub fn place_block(ctx: &ReducerContext, x: i32, y: i32, z: i32, block: u8) -> ReducerResult {
+    if block == BLOCK_AIR || block >= BLOCK_KINDS {
+        return Err(ReducerError::InvalidInput("Unknown block".to_string()));
+    }
+    let room_id = check_reach(ctx, x, y, z)?;
+    if block_at(ctx, room_id, x, y, z) != BLOCK_AIR {
+        return Err(ReducerError::InvalidInput("Block is already taken".to_string()));
+    }
+    set_block(ctx, room_id, x, y, z, block);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn break_block(ctx: &ReducerContext, x: i32, y: i32, z: i32) -> ReducerResult {
+    let room_id = check_reach(ctx, x, y, z)?;
+    if block_at(ctx, room_id, x, y, z) == BLOCK_AIR {
+        return Err(ReducerError::InvalidInput("Nothing to break".to_string()));
+    }
+    set_block(ctx, room_id, x, y, z, BLOCK_AIR);
+    Ok(())
+}
@@ -0,0 +1,259 @@
+use std::time::Duration;
+
+use spacetimedb::{ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp};
+
+use crate::admin;
+use crate::command::AttackKind;
+use crate::config;
+use crate::currency;
+use crate::ecs::{self, collider, entity, health, velocity, EntityKind};
+use crate::error::{ReducerError, ReducerResult};
+use crate::math::{self, DbVector3};
+use crate::rewind;
+use crate::spawn;
+use crate::stats;
+use crate::team;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum DamageSource {
+    Environment,
+    Admin,
+    Entity(u32), // Entity id of the attacker
+}
+
+// Component: the entity is dead and waiting to respawn.
+// Dead players cannot send commands and take no damage.
+#[spacetimedb::table(name = dead, public)]
+pub struct Dead {
+    #[primary_key]
+    pub entity_id: u32,
+    pub source: DamageSource,
+    pub died_at: Timestamp,
+    pub respawn_at: Timestamp,
+}
+
+// A death a player had not respawned from yet when their session ended, kept in their profile
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub struct SavedDeath {
+    pub source: DamageSource,
+    pub died_at: Timestamp,
+    pub respawn_at: Timestamp,
+}
+
+// Component: the entity cannot attack again before `ready_at`
+#[spacetimedb::table(name = attack_cooldown)]
+pub struct AttackCooldown {
+    #[primary_key]
+    pub entity_id: u32,
+    pub ready_at: Timestamp,
+}
+
+#[spacetimedb::table(name = respawn_timer, scheduled(respawn))]
+pub struct RespawnTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    entity_id: u32,
+}
+
+pub fn is_dead(ctx: &ReducerContext, entity_id: u32) -> bool {
+    ctx.db.dead().entity_id().find(entity_id).is_some()
+}
+
+// Live players and NPCs of the room that `attacker` can hit
+pub fn targets<'a>(ctx: &'a ReducerContext, room_id: u32, attacker: u32) -> impl Iterator<Item = u32> + 'a {
+    ctx.db
+        .entity()
+        .room_id()
+        .filter(room_id)
+        .filter(move |e| matches!(e.kind, EntityKind::Player | EntityKind::Npc) && e.entity_id != attacker)
+        .map(|e| e.entity_id)
+        .filter(|&entity_id| !is_dead(ctx, entity_id))
+}
+
+// Shared by every cooldown table: given the `ready_at` stored for an entity, if any,
+// the one to store next, or RateLimited while the current cooldown is still running
+pub fn next_ready_at(ctx: &ReducerContext, current: Option<Timestamp>, cooldown: Duration) -> Result<Timestamp, ReducerError> {
+    if current.is_some_and(|ready_at| ready_at > ctx.timestamp) {
+        return Err(ReducerError::RateLimited);
+    }
+    Ok(ctx.timestamp + TimeDuration::from_micros(cooldown.as_micros() as i64))
+}
+
+// Rate limit attacks of an entity, starting a new cooldown when allowed
+pub fn use_cooldown(ctx: &ReducerContext, entity_id: u32, cooldown: Duration) -> ReducerResult {
+    let current = ctx.db.attack_cooldown().entity_id().find(entity_id).map(|c| c.ready_at);
+    let ready_at = next_ready_at(ctx, current, cooldown)?;
+    ctx.db.attack_cooldown().entity_id().delete(entity_id);
+    ctx.db.attack_cooldown().insert(AttackCooldown { entity_id, ready_at });
+    Ok(())
+}
+
+// Damage pipeline: every source of damage goes through here.
+// Returns true if this hit killed the entity.
+pub fn apply_damage(ctx: &ReducerContext, entity_id: u32, amount: f32, source: DamageSource) -> bool {
+    if amount <= 0.0 || is_dead(ctx, entity_id) || !team::can_damage(ctx, entity_id, source) {
+        return false;
+    }
+    let Some(mut health) = ctx.db.health().entity_id().find(entity_id) else {
+        return false;
+    };
+
+    health.current = (health.current - amount).max(0.0);
+    let killed = health.current <= 0.0;
+    ctx.db.health().entity_id().update(health);
+
+    if killed {
+        kill(ctx, entity_id, source);
+    }
+    killed
+}
+
+// Range, damage and cooldown of each attack
+fn attack_stats(kind: AttackKind) -> (f32, f32, Duration) {
+    match kind {
+        AttackKind::Melee => (2.0, 35.0, Duration::from_millis(500)),
+        AttackKind::Hitscan => (100.0, 15.0, Duration::from_millis(150)),
+    }
+}
+
+// Instant attack along `direction`. Other players are tested where they were
+// at `seen_at`, clamped to the configured rewind window, NPCs where they are now.
+pub fn attack(ctx: &ReducerContext, attacker: u32, kind: AttackKind, direction: DbVector3, seen_at: Timestamp) -> ReducerResult {
+    let (range, damage, cooldown) = attack_stats(kind);
+    use_cooldown(ctx, attacker, cooldown)?;
+    let start = ecs::position_of(ctx, attacker).ok_or(ReducerError::NotConnected)?;
+    let room_id = ecs::room_of(ctx, attacker).ok_or(ReducerError::NotConnected)?;
+    let end = start + direction.normalized() * range;
+
+    let max_rewind = Duration::from_millis(config::get(ctx).max_rewind_ms);
+    let at = rewind::rewind_target(ctx, seen_at, max_rewind);
+    let target = targets(ctx, room_id, attacker)
+        .filter_map(|entity_id| {
+            let position = rewind::position_at(ctx, entity_id, at)?;
+            let radius = ctx.db.collider().entity_id().find(entity_id)?.radius;
+            math::segment_hits_sphere(start, end, position, radius)
+                .then(|| (entity_id, (position - start).sqr_magnitude()))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity_id, _)| entity_id);
+
+    if let Some(target) = target {
+        apply_damage(ctx, target, damage, DamageSource::Entity(attacker));
+    }
+    Ok(())
+}
+
+fn kill(ctx: &ReducerContext, entity_id: u32, source: DamageSource) {
+    log::info!("Entity {} killed by {:?}", entity_id, source);
+    team::record_kill(ctx, entity_id, source);
+    stats::record_kill(ctx, entity_id, source);
+    currency::reward_kill(ctx, entity_id, source);
+    let is_player = ctx
+        .db
+        .entity()
+        .entity_id()
+        .find(entity_id)
+        .is_some_and(|e| e.kind == EntityKind::Player);
+    if !is_player {
+        ecs::despawn(ctx, entity_id);
+        return;
+    }
+
+    // Players stay around as dead until the respawn timer fires
+    if let Some(mut velocity) = ctx.db.velocity().entity_id().find(entity_id) {
+        velocity.linear = DbVector3::ZERO;
+        ctx.db.velocity().entity_id().update(velocity);
+    }
+    let delay = TimeDuration::from_micros(config::get(ctx).respawn_delay_ms as i64 * 1_000);
+    mark_dead(
+        ctx,
+        entity_id,
+        SavedDeath {
+            source,
+            died_at: ctx.timestamp,
+            respawn_at: ctx.timestamp + delay,
+        },
+    );
+}
+
+fn mark_dead(ctx: &ReducerContext, entity_id: u32, death: SavedDeath) {
+    ctx.db.dead().insert(Dead {
+        entity_id,
+        source: death.source,
+        died_at: death.died_at,
+        respawn_at: death.respawn_at,
+    });
+    ctx.db.respawn_timer().insert(RespawnTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(death.respawn_at),
+        entity_id,
+    });
+}
+
+// The death to carry over when the player's session ends now, if they are dead
+pub fn saved_death(ctx: &ReducerContext, entity_id: u32) -> Option<SavedDeath> {
+    ctx.db.dead().entity_id().find(entity_id).map(|dead| SavedDeath {
+        source: dead.source,
+        died_at: dead.died_at,
+        respawn_at: dead.respawn_at,
+    })
+}
+
+// Put a reconnecting player back into the death they left in. If the respawn came due
+// while they were away they start out alive at a spawn point instead of where they fell.
+pub fn resume_death(ctx: &ReducerContext, entity_id: u32, death: SavedDeath) {
+    if death.respawn_at <= ctx.timestamp {
+        let room_id = ecs::room_of(ctx, entity_id).unwrap_or_default();
+        let spawn = spawn::select(ctx, room_id, team::team_of(ctx, entity_id));
+        ecs::set_transform(ctx, entity_id, spawn.position, spawn.rotation);
+        return;
+    }
+    if let Some(mut health) = ctx.db.health().entity_id().find(entity_id) {
+        health.current = 0.0;
+        ctx.db.health().entity_id().update(health);
+    }
+    mark_dead(ctx, entity_id, death);
+}
+
+// Reducer: Scheduled, bring a dead player back with full health
+#[spacetimedb::reducer]
+pub fn respawn(ctx: &ReducerContext, timer: RespawnTimer) -> ReducerResult {
+    if ctx.sender != ctx.identity() {
+        return Err(ReducerError::PermissionDenied("Respawn is scheduled by the server".to_string()));
+    }
+    // The player may have left while dead, their entity is gone then
+    let Some(dead) = ctx.db.dead().entity_id().find(timer.entity_id) else {
+        return Ok(());
+    };
+    // A timer left over from an earlier death, e.g. one a match reset revived them from,
+    // must not cut the current one short
+    if timer.scheduled_at != ScheduleAt::Time(dead.respawn_at) {
+        return Ok(());
+    }
+    ctx.db.dead().entity_id().delete(timer.entity_id);
+    if let Some(mut health) = ctx.db.health().entity_id().find(timer.entity_id) {
+        health.current = health.max;
+        ctx.db.health().entity_id().update(health);
+    }
+    let room_id = ecs::room_of(ctx, timer.entity_id).unwrap_or_default();
+    let spawn = spawn::select(ctx, room_id, team::team_of(ctx, timer.entity_id));
+    ecs::set_transform(ctx, timer.entity_id, spawn.position, spawn.rotation);
+    log::info!("Entity {} respawned", timer.entity_id);
+    Ok(())
+}
+
+// Reducer: Admin tool to hurt an entity through the normal pipeline
+#[spacetimedb::reducer]
+pub fn deal_damage(ctx: &ReducerContext, entity_id: u32, amount: f32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if !amount.is_finite() || amount <= 0.0 {
+        return Err(ReducerError::InvalidInput("Damage must be positive".to_string()));
+    }
+    if ctx.db.health().entity_id().find(entity_id).is_none() {
+        return Err(ReducerError::NotFound("Entity has no health".to_string()));
+    }
+    apply_damage(ctx, entity_id, amount, DamageSource::Admin);
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use crate::math;
 use math::DbVector3;
 
-use spacetimedb::SpacetimeType;
+use spacetimedb::{SpacetimeType, Timestamp};
 
 
 // Define a struct for Move command data
@@ -10,10 +10,40 @@ pub struct MoveCommand {
     pub direction: DbVector3,
 }
 
+// Fire a projectile from the player towards `direction`
+#[derive(SpacetimeType)]
+pub struct FireCommand {
+    pub direction: DbVector3,
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum AttackKind {
+    Melee,   // Short range, heavy hit
+    Hitscan, // Long range instant ray
+}
+
+// Instant attack towards `direction`, tested against where targets were at `seen_at`
+#[derive(SpacetimeType)]
+pub struct AttackCommand {
+    pub kind: AttackKind,
+    pub direction: DbVector3,
+    // Server time of the latest world state the client had when attacking
+    pub seen_at: Timestamp,
+}
+
+// Harvest a resource node in reach of the player
+#[derive(SpacetimeType)]
+pub struct GatherCommand {
+    pub node_id: u32,
+}
+
 // Enum with unit and newtype variants
 #[derive(SpacetimeType)]
 pub enum Command {
     Move(MoveCommand), // Newtype: wraps MoveCommand
     Jump,             // Unit
-    // Add more: Attack(AttackCommand), Interact(InteractCommand), etc.
+    Fire(FireCommand),
+    Attack(AttackCommand),
+    Gather(GatherCommand),
+    // Add more: Interact(InteractCommand), etc.
 }
\ No newline at end of file
@@ -0,0 +1,137 @@
+use spacetimedb::rand::Rng;
+use spacetimedb::{ReducerContext, Table};
+
+use crate::admin;
+use crate::error::{ReducerError, ReducerResult};
+use crate::rewind;
+use crate::spawn::SpawnStrategy;
+
+const CONFIG_ID: u32 = 0;
+
+// Tunables for the whole world, a single row seeded at init
+#[spacetimedb::table(name = world_config, public)]
+#[derive(Clone)]
+pub struct WorldConfig {
+    #[primary_key]
+    id: u32,
+    pub respawn_delay_ms: u64,
+    pub spawn_strategy: SpawnStrategy,
+    // How far back hitscan attacks may rewind other players
+    pub max_rewind_ms: u64,
+    // Whether players can hurt their own teammates
+    pub friendly_fire: bool,
+    // Match phase lengths, see `match_state`
+    pub warmup_secs: u32,
+    pub match_secs: u32,
+    pub results_secs: u32,
+    // Team score that ends a match early, 0 plays until time runs out
+    pub score_limit: u32,
+    // Terrain is generated from this, clients read it to build the same ground
+    pub terrain_seed: u64,
+    // Real seconds one day and night of the world clock lasts
+    pub day_length_secs: u32,
+}
+
+impl Default for WorldConfig {
+    fn default() -> Self {
+        Self {
+            id: CONFIG_ID,
+            respawn_delay_ms: 5_000,
+            spawn_strategy: SpawnStrategy::LeastCrowded,
+            max_rewind_ms: 200,
+            friendly_fire: false,
+            warmup_secs: 30,
+            match_secs: 600,
+            results_secs: 10,
+            score_limit: 20,
+            terrain_seed: 0,
+            day_length_secs: 1_200,
+        }
+    }
+}
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.world_config().id().find(CONFIG_ID).is_none() {
+        ctx.db.world_config().insert(WorldConfig {
+            terrain_seed: ctx.rng().gen(),
+            ..WorldConfig::default()
+        });
+    }
+}
+
+pub fn get(ctx: &ReducerContext) -> WorldConfig {
+    ctx.db.world_config().id().find(CONFIG_ID).unwrap_or_default()
+}
+
+fn update(ctx: &ReducerContext, config: WorldConfig) {
+    if ctx.db.world_config().id().find(CONFIG_ID).is_some() {
+        ctx.db.world_config().id().update(config);
+    } else {
+        ctx.db.world_config().insert(config);
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn set_respawn_delay(ctx: &ReducerContext, delay_ms: u64) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if delay_ms > 60_000 {
+        return Err(ReducerError::InvalidInput("Respawn delay is capped at 60s".to_string()));
+    }
+    update(ctx, WorldConfig { respawn_delay_ms: delay_ms, ..get(ctx) });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_spawn_strategy(ctx: &ReducerContext, strategy: SpawnStrategy) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    update(ctx, WorldConfig { spawn_strategy: strategy, ..get(ctx) });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_max_rewind(ctx: &ReducerContext, rewind_ms: u64) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if rewind_ms > rewind::MAX_REWIND.as_millis() as u64 {
+        return Err(ReducerError::InvalidInput(format!(
+            "Rewind is capped at {}ms",
+            rewind::MAX_REWIND.as_millis()
+        )));
+    }
+    update(ctx, WorldConfig { max_rewind_ms: rewind_ms, ..get(ctx) });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_friendly_fire(ctx: &ReducerContext, enabled: bool) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    update(ctx, WorldConfig { friendly_fire: enabled, ..get(ctx) });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_match_rules(ctx: &ReducerContext, warmup_secs: u32, match_secs: u32, score_limit: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if match_secs == 0 {
+        return Err(ReducerError::InvalidInput("Matches need a time limit".to_string()));
+    }
+    update(ctx, WorldConfig { warmup_secs, match_secs, score_limit, ..get(ctx) });
+    Ok(())
+}
+
+// Reducer: Regenerate the terrain, everything standing on it snaps to the new ground next tick
+#[spacetimedb::reducer]
+pub fn set_terrain_seed(ctx: &ReducerContext, seed: u64) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    update(ctx, WorldConfig { terrain_seed: seed, ..get(ctx) });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_day_length(ctx: &ReducerContext, day_length_secs: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if day_length_secs < 60 {
+        return Err(ReducerError::InvalidInput("Days last at least 60s".to_string()));
+    }
+    update(ctx, WorldConfig { day_length_secs, ..get(ctx) });
+    Ok(())
+}
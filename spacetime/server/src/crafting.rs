@@ -0,0 +1,219 @@
+use std::time::Duration;
+
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp};
+
+use crate::admin;
+use crate::combat;
+use crate::ecs;
+use crate::error::{ReducerError, ReducerResult};
+use crate::inventory::{self, ItemStack};
+use crate::item_drop;
+use crate::math::DbVector3;
+use crate::player;
+
+// Players have to stand this close to a station to use it
+const STATION_RANGE: f32 = 4.0;
+// Timed crafts one player can have waiting at once
+const MAX_QUEUED_CRAFTS: usize = 5;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum StationKind {
+    Workbench,
+    Furnace,
+}
+
+#[spacetimedb::table(name = recipe, public)]
+pub struct Recipe {
+    #[primary_key]
+    #[auto_inc]
+    pub recipe_id: u32,
+    #[unique]
+    pub name: String,
+    pub inputs: Vec<ItemStack>,
+    pub outputs: Vec<ItemStack>,
+    // 0 crafts right away
+    pub craft_time_ms: u32,
+    // Station the player must stand next to, None crafts anywhere
+    pub station: Option<StationKind>,
+}
+
+// Where stations stand. Shared by every room, like static colliders.
+#[spacetimedb::table(name = crafting_station, public)]
+pub struct CraftingStation {
+    #[primary_key]
+    #[auto_inc]
+    pub station_id: u32,
+    pub kind: StationKind,
+    pub position: DbVector3,
+}
+
+// A timed craft whose inputs are already paid, outputs are handed over at `scheduled_at`
+#[spacetimedb::table(name = craft_job, public, scheduled(finish_craft))]
+pub struct CraftJob {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    #[index(btree)]
+    pub owner: Identity,
+    pub recipe_id: u32,
+    pub started_at: Timestamp,
+    pub ready_at: Timestamp,
+}
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.recipe().count() > 0 {
+        return;
+    }
+    let (Some(ore), Some(ingot)) = (inventory::item_named(ctx, "Iron Ore"), inventory::item_named(ctx, "Iron Ingot")) else {
+        return;
+    };
+    ctx.db.recipe().insert(Recipe {
+        recipe_id: 0,
+        name: "Iron Ingot".to_string(),
+        inputs: vec![ItemStack { item_id: ore.item_id, quantity: 2 }],
+        outputs: vec![ItemStack { item_id: ingot.item_id, quantity: 1 }],
+        craft_time_ms: 5000,
+        station: Some(StationKind::Furnace),
+    });
+}
+
+fn near_station(ctx: &ReducerContext, position: DbVector3, kind: StationKind) -> bool {
+    ctx.db
+        .crafting_station()
+        .iter()
+        .any(|s| s.kind == kind && (s.position - position).sqr_magnitude() <= STATION_RANGE * STATION_RANGE)
+}
+
+fn deliver(ctx: &ReducerContext, owner: Identity, recipe: &Recipe) {
+    for output in &recipe.outputs {
+        item_drop::give_or_drop(ctx, owner, output.item_id, output.quantity);
+    }
+}
+
+// Reducer: Craft a recipe, paying its inputs up front
+#[spacetimedb::reducer]
+pub fn craft(ctx: &ReducerContext, recipe_id: u32) -> ReducerResult {
+    let player = ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    if combat::is_dead(ctx, player.entity_id) {
+        return Err(ReducerError::Dead);
+    }
+    let recipe = ctx
+        .db
+        .recipe()
+        .recipe_id()
+        .find(recipe_id)
+        .ok_or_else(|| ReducerError::NotFound("No such recipe".to_string()))?;
+    if let Some(kind) = recipe.station {
+        let position = ecs::position_of(ctx, player.entity_id).ok_or(ReducerError::NotConnected)?;
+        if !near_station(ctx, position, kind) {
+            return Err(ReducerError::InvalidInput(format!("Needs a {:?} nearby", kind)));
+        }
+    }
+    let queued: Vec<CraftJob> = ctx.db.craft_job().owner().filter(ctx.sender).collect();
+    if recipe.craft_time_ms > 0 && queued.len() >= MAX_QUEUED_CRAFTS {
+        return Err(ReducerError::RateLimited);
+    }
+    for input in &recipe.inputs {
+        if inventory::count(ctx, ctx.sender, input.item_id) < input.quantity {
+            return Err(ReducerError::InvalidInput("Missing ingredients".to_string()));
+        }
+    }
+    for input in &recipe.inputs {
+        inventory::take(ctx, ctx.sender, input.item_id, input.quantity)?;
+    }
+
+    if recipe.craft_time_ms == 0 {
+        deliver(ctx, ctx.sender, &recipe);
+        return Ok(());
+    }
+    // Crafts run one after another
+    let started_at = queued.iter().map(|j| j.ready_at).max().map_or(ctx.timestamp, |last| last.max(ctx.timestamp));
+    let craft_time = Duration::from_millis(recipe.craft_time_ms as u64);
+    let ready_at = started_at + TimeDuration::from_micros(craft_time.as_micros() as i64);
+    ctx.db.craft_job().insert(CraftJob {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(ready_at),
+        owner: ctx.sender,
+        recipe_id,
+        started_at,
+        ready_at,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn finish_craft(ctx: &ReducerContext, job: CraftJob) -> ReducerResult {
+    if ctx.sender != ctx.identity() {
+        return Err(ReducerError::PermissionDenied("Crafts are finished by the server".to_string()));
+    }
+    // The recipe may have been removed since, the inputs are lost with it
+    if let Some(recipe) = ctx.db.recipe().recipe_id().find(job.recipe_id) {
+        deliver(ctx, job.owner, &recipe);
+    }
+    Ok(())
+}
+
+// Reducer: Admin tool to add a recipe
+#[spacetimedb::reducer]
+pub fn add_recipe(
+    ctx: &ReducerContext,
+    name: String,
+    inputs: Vec<ItemStack>,
+    outputs: Vec<ItemStack>,
+    craft_time_ms: u32,
+    station: Option<StationKind>,
+) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if name.trim().is_empty() || inputs.is_empty() || outputs.is_empty() {
+        return Err(ReducerError::InvalidInput("Recipes need a name, inputs and outputs".to_string()));
+    }
+    for stack in inputs.iter().chain(&outputs) {
+        inventory::definition(ctx, stack.item_id)?;
+        if stack.quantity == 0 {
+            return Err(ReducerError::InvalidInput("Stacks cannot be empty".to_string()));
+        }
+    }
+    if ctx.db.recipe().name().find(&name).is_some() {
+        return Err(ReducerError::InvalidInput("Recipe name is taken".to_string()));
+    }
+    ctx.db.recipe().insert(Recipe {
+        recipe_id: 0,
+        name,
+        inputs,
+        outputs,
+        craft_time_ms,
+        station,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_recipe(ctx: &ReducerContext, recipe_id: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if !ctx.db.recipe().recipe_id().delete(recipe_id) {
+        return Err(ReducerError::NotFound("No such recipe".to_string()));
+    }
+    Ok(())
+}
+
+// Reducer: Admin tool to put a crafting station in the world
+#[spacetimedb::reducer]
+pub fn add_crafting_station(ctx: &ReducerContext, kind: StationKind, position: DbVector3) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    ctx.db.crafting_station().insert(CraftingStation {
+        station_id: 0,
+        kind,
+        position,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_crafting_station(ctx: &ReducerContext, station_id: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if !ctx.db.crafting_station().station_id().delete(station_id) {
+        return Err(ReducerError::NotFound("No such station".to_string()));
+    }
+    Ok(())
+}
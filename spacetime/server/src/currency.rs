@@ -0,0 +1,133 @@
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table, Timestamp};
+
+use crate::admin;
+use crate::combat::DamageSource;
+use crate::ecs::{entity, EntityKind};
+use crate::error::{ReducerError, ReducerResult};
+use crate::player;
+
+// Coins a player gets for killing an NPC
+const NPC_LOOT: u64 = 5;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum LedgerReason {
+    Loot,     // Dropped by something the player killed
+    Trade,    // Part of a trade with another player
+    Purchase, // Spent on or earned from a sale
+    Transfer, // Sent by one player to another
+    Admin,    // Granted or taken by an admin
+}
+
+// Coins a player holds. Kept by identity so it survives reconnects.
+#[spacetimedb::table(name = wallet, public)]
+pub struct Wallet {
+    #[primary_key]
+    pub identity: Identity,
+    pub balance: u64,
+}
+
+// Ledger of every balance change, rows are only ever inserted.
+// Summing `amount` over an identity gives its balance.
+#[spacetimedb::table(name = ledger_entry, public)]
+pub struct LedgerEntry {
+    #[primary_key]
+    #[auto_inc]
+    pub entry_id: u64,
+    #[index(btree)]
+    pub identity: Identity,
+    // Positive for money in, negative for money out
+    pub amount: i64,
+    pub balance_after: u64,
+    pub reason: LedgerReason,
+    // The other side of trades and transfers
+    pub counterparty: Option<Identity>,
+    pub created_at: Timestamp,
+}
+
+pub fn balance_of(ctx: &ReducerContext, identity: Identity) -> u64 {
+    ctx.db.wallet().identity().find(identity).map_or(0, |w| w.balance)
+}
+
+fn record(ctx: &ReducerContext, identity: Identity, amount: i64, balance: u64, reason: LedgerReason, counterparty: Option<Identity>) {
+    if ctx.db.wallet().identity().find(identity).is_some() {
+        ctx.db.wallet().identity().update(Wallet { identity, balance });
+    } else {
+        ctx.db.wallet().insert(Wallet { identity, balance });
+    }
+    ctx.db.ledger_entry().insert(LedgerEntry {
+        entry_id: 0,
+        identity,
+        amount,
+        balance_after: balance,
+        reason,
+        counterparty,
+        created_at: ctx.timestamp,
+    });
+}
+
+fn check_amount(amount: u64) -> Result<i64, ReducerError> {
+    match i64::try_from(amount) {
+        Ok(amount) if amount > 0 => Ok(amount),
+        _ => Err(ReducerError::InvalidInput("Invalid amount".to_string())),
+    }
+}
+
+pub fn credit(ctx: &ReducerContext, identity: Identity, amount: u64, reason: LedgerReason, counterparty: Option<Identity>) -> ReducerResult {
+    let signed = check_amount(amount)?;
+    let balance = balance_of(ctx, identity)
+        .checked_add(amount)
+        .ok_or_else(|| ReducerError::Internal("Balance overflow".to_string()))?;
+    record(ctx, identity, signed, balance, reason, counterparty);
+    Ok(())
+}
+
+// Take coins out of a wallet, failing rather than going below zero
+pub fn debit(ctx: &ReducerContext, identity: Identity, amount: u64, reason: LedgerReason, counterparty: Option<Identity>) -> ReducerResult {
+    let signed = check_amount(amount)?;
+    let balance = balance_of(ctx, identity)
+        .checked_sub(amount)
+        .ok_or_else(|| ReducerError::InvalidInput("Not enough coins".to_string()))?;
+    record(ctx, identity, -signed, balance, reason, counterparty);
+    Ok(())
+}
+
+// Pay out loot to the player behind a kill, only NPCs carry any
+pub fn reward_kill(ctx: &ReducerContext, victim: u32, source: DamageSource) {
+    let DamageSource::Entity(killer) = source else {
+        return;
+    };
+    let is_npc = ctx.db.entity().entity_id().find(victim).is_some_and(|e| e.kind == EntityKind::Npc);
+    let Some(killer) = ctx.db.player().entity_id().find(killer) else {
+        return;
+    };
+    if is_npc {
+        if let Err(e) = credit(ctx, killer.identity, NPC_LOOT, LedgerReason::Loot, None) {
+            log::warn!("Loot for {} failed: {}", killer.identity, e);
+        }
+    }
+}
+
+// Reducer: Send coins to another player
+#[spacetimedb::reducer]
+pub fn transfer(ctx: &ReducerContext, to: Identity, amount: u64) -> ReducerResult {
+    ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    if to == ctx.sender {
+        return Err(ReducerError::InvalidInput("Cannot transfer to yourself".to_string()));
+    }
+    if ctx.db.player().identity().find(to).is_none() && ctx.db.wallet().identity().find(to).is_none() {
+        return Err(ReducerError::NotFound("No such player".to_string()));
+    }
+    debit(ctx, ctx.sender, amount, LedgerReason::Transfer, Some(to))?;
+    credit(ctx, to, amount, LedgerReason::Transfer, Some(ctx.sender))
+}
+
+// Reducer: Admin tool to add coins to a wallet, or take them away with a negative amount
+#[spacetimedb::reducer]
+pub fn grant_currency(ctx: &ReducerContext, identity: Identity, amount: i64) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if amount >= 0 {
+        credit(ctx, identity, amount as u64, LedgerReason::Admin, None)
+    } else {
+        debit(ctx, identity, amount.unsigned_abs(), LedgerReason::Admin, None)
+    }
+}
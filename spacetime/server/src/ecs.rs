@@ -0,0 +1,145 @@
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+
+use crate::combat::{attack_cooldown, dead};
+use crate::gathering::gather_cooldown;
+use crate::item_drop;
+use crate::navigation;
+use crate::npc;
+use crate::projectile::projectile;
+use crate::rewind;
+use crate::team;
+use crate::math::DbVector3;
+
+// Server side counterpart of `src/ecs.zig`: an entity is just an id,
+// everything else about it lives in component tables keyed by that id.
+// Systems are plain functions run from the scheduled tick.
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum EntityKind {
+    Player,
+    Projectile,
+    Npc,
+    ItemDrop,
+}
+
+#[spacetimedb::table(name = entity, public)]
+pub struct Entity {
+    #[primary_key]
+    #[auto_inc]
+    pub entity_id: u32,
+    pub kind: EntityKind,
+    // Room the entity lives in, systems only see entities of the room they run for
+    #[index(btree)]
+    pub room_id: u32,
+}
+
+#[spacetimedb::table(name = transform, public)]
+pub struct Transform {
+    #[primary_key]
+    pub entity_id: u32,
+    pub position: DbVector3,
+    pub rotation: DbVector3,
+}
+
+// Units per second
+#[spacetimedb::table(name = velocity, public)]
+pub struct Velocity {
+    #[primary_key]
+    pub entity_id: u32,
+    pub linear: DbVector3,
+}
+
+// Sphere around the transform position
+#[spacetimedb::table(name = collider, public)]
+pub struct Collider {
+    #[primary_key]
+    pub entity_id: u32,
+    pub radius: f32,
+}
+
+#[spacetimedb::table(name = health, public)]
+pub struct Health {
+    #[primary_key]
+    pub entity_id: u32,
+    pub current: f32,
+    pub max: f32,
+}
+
+// Identity in control of the entity
+#[spacetimedb::table(name = owner, public)]
+pub struct Owner {
+    #[primary_key]
+    pub entity_id: u32,
+    #[index(btree)]
+    pub identity: Identity,
+}
+
+// Create a bare entity, components are inserted by the caller
+pub fn spawn(ctx: &ReducerContext, kind: EntityKind, room_id: u32) -> u32 {
+    ctx.db.entity().insert(Entity { entity_id: 0, kind, room_id }).entity_id
+}
+
+// Delete an entity along with every component it has
+pub fn despawn(ctx: &ReducerContext, entity_id: u32) {
+    ctx.db.transform().entity_id().delete(entity_id);
+    ctx.db.velocity().entity_id().delete(entity_id);
+    ctx.db.collider().entity_id().delete(entity_id);
+    ctx.db.health().entity_id().delete(entity_id);
+    ctx.db.owner().entity_id().delete(entity_id);
+    ctx.db.dead().entity_id().delete(entity_id);
+    ctx.db.attack_cooldown().entity_id().delete(entity_id);
+    ctx.db.gather_cooldown().entity_id().delete(entity_id);
+    ctx.db.projectile().entity_id().delete(entity_id);
+    rewind::clear(ctx, entity_id);
+    npc::clear(ctx, entity_id);
+    item_drop::clear(ctx, entity_id);
+    navigation::clear(ctx, entity_id);
+    team::remove(ctx, entity_id);
+    ctx.db.entity().entity_id().delete(entity_id);
+}
+
+pub fn room_of(ctx: &ReducerContext, entity_id: u32) -> Option<u32> {
+    ctx.db.entity().entity_id().find(entity_id).map(|e| e.room_id)
+}
+
+pub fn set_room(ctx: &ReducerContext, entity_id: u32, room_id: u32) {
+    if let Some(mut entity) = ctx.db.entity().entity_id().find(entity_id) {
+        entity.room_id = room_id;
+        ctx.db.entity().entity_id().update(entity);
+    }
+}
+
+pub fn position_of(ctx: &ReducerContext, entity_id: u32) -> Option<DbVector3> {
+    ctx.db.transform().entity_id().find(entity_id).map(|t| t.position)
+}
+
+pub fn set_position(ctx: &ReducerContext, entity_id: u32, position: DbVector3) {
+    if let Some(mut transform) = ctx.db.transform().entity_id().find(entity_id) {
+        transform.position = position;
+        ctx.db.transform().entity_id().update(transform);
+    }
+}
+
+pub fn set_transform(ctx: &ReducerContext, entity_id: u32, position: DbVector3, rotation: DbVector3) {
+    if let Some(mut transform) = ctx.db.transform().entity_id().find(entity_id) {
+        transform.position = position;
+        transform.rotation = rotation;
+        ctx.db.transform().entity_id().update(transform);
+    }
+}
+
+// System: integrate velocity into transform for every moving entity of the room
+pub fn movement_system(ctx: &ReducerContext, room_id: u32, dt: f32) {
+    for entity in ctx.db.entity().room_id().filter(room_id) {
+        let Some(velocity) = ctx.db.velocity().entity_id().find(entity.entity_id) else {
+            continue;
+        };
+        if velocity.linear.sqr_magnitude() == 0.0 {
+            continue;
+        }
+        if let Some(mut transform) = ctx.db.transform().entity_id().find(velocity.entity_id) {
+            transform.position += velocity.linear * dt;
+            ctx.db.transform().entity_id().update(transform);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+
+// Errors returned by the module's reducers.
+//
+// SpacetimeDB only sends the `Display` string of a failed reducer to the client,
+// so every variant is written as `Code` or `Code: detail`. The client library
+// parses the code back into its own `ReducerError` and forwards it over FFI.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReducerError {
+    NotConnected,             // Sender has no player row
+    Dead,                     // Sender's player is waiting to respawn
+    InvalidInput(String),     // Arguments failed validation
+    RateLimited,              // Sender is calling too often
+    PermissionDenied(String), // Not an admin, banned, muted...
+    NotFound(String),         // Target row does not exist
+    Internal(String),         // Should not happen, but beats a panic
+}
+
+impl ReducerError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            ReducerError::NotConnected => "NotConnected",
+            ReducerError::Dead => "Dead",
+            ReducerError::InvalidInput(_) => "InvalidInput",
+            ReducerError::RateLimited => "RateLimited",
+            ReducerError::PermissionDenied(_) => "PermissionDenied",
+            ReducerError::NotFound(_) => "NotFound",
+            ReducerError::Internal(_) => "Internal",
+        }
+    }
+
+    fn detail(&self) -> Option<&str> {
+        match self {
+            ReducerError::InvalidInput(msg)
+            | ReducerError::PermissionDenied(msg)
+            | ReducerError::NotFound(msg)
+            | ReducerError::Internal(msg) => Some(msg),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ReducerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.detail() {
+            Some(detail) => write!(f, "{}: {}", self.code(), detail),
+            None => write!(f, "{}", self.code()),
+        }
+    }
+}
+
+impl std::error::Error for ReducerError {}
+
+pub type ReducerResult = Result<(), ReducerError>;
@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use spacetimedb::{ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp};
+
+use crate::admin;
+use crate::combat;
+use crate::ecs;
+use crate::error::{ReducerError, ReducerResult};
+use crate::inventory;
+use crate::item_drop;
+use crate::math::DbVector3;
+use crate::terrain;
+use crate::Player;
+
+const GATHER_COOLDOWN: Duration = Duration::from_millis(500);
+// Players have to stand this close to a node to gather from it
+const GATHER_RANGE: f32 = 3.0;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum ResourceKind {
+    Tree, // Yields wood
+    Ore,  // Yields iron ore
+}
+
+// Something in the world players can harvest. Shared by every room, like crafting stations.
+#[spacetimedb::table(name = resource_node, public)]
+pub struct ResourceNode {
+    #[primary_key]
+    #[auto_inc]
+    pub node_id: u32,
+    pub kind: ResourceKind,
+    pub position: DbVector3,
+    // Item handed out per gather
+    pub item_id: u32,
+    pub yield_per_gather: u32,
+    // Gathers left before the node is depleted
+    pub health: u32,
+    pub max_health: u32,
+    pub respawn_secs: u32,
+    // Set while depleted
+    pub respawn_at: Option<Timestamp>,
+}
+
+// Component: the entity cannot gather again before `ready_at`. Kept apart from the
+// attack cooldown so gathering and fighting do not hold each other up.
+#[spacetimedb::table(name = gather_cooldown)]
+pub struct GatherCooldown {
+    #[primary_key]
+    pub entity_id: u32,
+    pub ready_at: Timestamp,
+}
+
+#[spacetimedb::table(name = node_respawn_timer, scheduled(respawn_node))]
+pub struct NodeRespawnTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    node_id: u32,
+}
+
+// Item, yield per gather, gathers until depleted and seconds to respawn of each kind
+fn kind_stats(kind: ResourceKind) -> (&'static str, u32, u32, u32) {
+    match kind {
+        ResourceKind::Tree => ("Wood", 2, 5, 60),
+        ResourceKind::Ore => ("Iron Ore", 1, 4, 120),
+    }
+}
+
+fn add_node(ctx: &ReducerContext, kind: ResourceKind, position: DbVector3) -> ReducerResult {
+    let (item, yield_per_gather, max_health, respawn_secs) = kind_stats(kind);
+    let item = inventory::item_named(ctx, item).ok_or_else(|| ReducerError::NotFound(format!("No {} item", item)))?;
+    ctx.db.resource_node().insert(ResourceNode {
+        node_id: 0,
+        kind,
+        position,
+        item_id: item.item_id,
+        yield_per_gather,
+        health: max_health,
+        max_health,
+        respawn_secs,
+        respawn_at: None,
+    });
+    Ok(())
+}
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.resource_node().count() > 0 {
+        return;
+    }
+    let nodes = [
+        (ResourceKind::Tree, 15.0, 15.0),
+        (ResourceKind::Tree, -15.0, 12.0),
+        (ResourceKind::Ore, 12.0, -14.0),
+    ];
+    for (kind, x, z) in nodes {
+        let position = DbVector3::new(x, terrain::height_at(ctx, x, z), z);
+        if let Err(e) = add_node(ctx, kind, position) {
+            log::warn!("Skipping {:?} node: {}", kind, e);
+        }
+    }
+}
+
+// Take one gather worth of resources out of a node for the player
+pub fn gather(ctx: &ReducerContext, player: &Player, node_id: u32) -> ReducerResult {
+    let entity_id = player.entity_id;
+    let current = ctx.db.gather_cooldown().entity_id().find(entity_id).map(|c| c.ready_at);
+    let ready_at = combat::next_ready_at(ctx, current, GATHER_COOLDOWN)?;
+    ctx.db.gather_cooldown().entity_id().delete(entity_id);
+    ctx.db.gather_cooldown().insert(GatherCooldown { entity_id, ready_at });
+    let mut node = ctx
+        .db
+        .resource_node()
+        .node_id()
+        .find(node_id)
+        .ok_or_else(|| ReducerError::NotFound("No such resource node".to_string()))?;
+    if node.health == 0 {
+        return Err(ReducerError::InvalidInput("Node is depleted".to_string()));
+    }
+    let position = ecs::position_of(ctx, player.entity_id).ok_or(ReducerError::NotConnected)?;
+    if (node.position - position).sqr_magnitude() > GATHER_RANGE * GATHER_RANGE {
+        return Err(ReducerError::InvalidInput("Node is out of reach".to_string()));
+    }
+
+    item_drop::give_or_drop(ctx, player.identity, node.item_id, node.yield_per_gather);
+    node.health -= 1;
+    if node.health == 0 {
+        let respawn_at = ctx.timestamp + TimeDuration::from_micros(node.respawn_secs as i64 * 1_000_000);
+        node.respawn_at = Some(respawn_at);
+        ctx.db.node_respawn_timer().insert(NodeRespawnTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Time(respawn_at),
+            node_id,
+        });
+    }
+    ctx.db.resource_node().node_id().update(node);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn respawn_node(ctx: &ReducerContext, timer: NodeRespawnTimer) -> ReducerResult {
+    if ctx.sender != ctx.identity() {
+        return Err(ReducerError::PermissionDenied("Nodes are respawned by the server".to_string()));
+    }
+    if let Some(mut node) = ctx.db.resource_node().node_id().find(timer.node_id) {
+        node.health = node.max_health;
+        node.respawn_at = None;
+        ctx.db.resource_node().node_id().update(node);
+    }
+    Ok(())
+}
+
+// Reducer: Admin tool to put a resource node in the world
+#[spacetimedb::reducer]
+pub fn add_resource_node(ctx: &ReducerContext, kind: ResourceKind, position: DbVector3) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    add_node(ctx, kind, position)
+}
+
+#[spacetimedb::reducer]
+pub fn remove_resource_node(ctx: &ReducerContext, node_id: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if !ctx.db.resource_node().node_id().delete(node_id) {
+        return Err(ReducerError::NotFound("No such resource node".to_string()));
+    }
+    Ok(())
+}
@@ -0,0 +1,341 @@
+use spacetimedb::{Identity, ReducerContext, SpacetimeType, Table};
+
+use crate::admin;
+use crate::combat;
+use crate::ecs::health;
+use crate::error::{ReducerError, ReducerResult};
+use crate::item_drop;
+use crate::player;
+use crate::voxel;
+use crate::Player;
+
+// Slots every player has, numbered from 0
+pub const INVENTORY_SIZE: u32 = 24;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum ItemKind {
+    Material,  // Only good for crafting and trading
+    Block(u8), // Block id it stands for, see `voxel`
+    Food(f32), // Health restored when used
+}
+
+// Some amount of one item, as used by recipes and trades
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub struct ItemStack {
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+#[spacetimedb::table(name = item_definition, public)]
+pub struct ItemDefinition {
+    #[primary_key]
+    #[auto_inc]
+    pub item_id: u32,
+    #[unique]
+    pub name: String,
+    // Most items one slot can hold
+    pub max_stack: u32,
+    pub kind: ItemKind,
+}
+
+// One non-empty slot of a player's inventory. Kept by identity so it survives reconnects.
+#[spacetimedb::table(name = inventory_slot, public)]
+pub struct InventorySlot {
+    #[primary_key]
+    #[auto_inc]
+    pub slot_id: u64,
+    #[index(btree)]
+    pub owner: Identity,
+    // Position in the inventory, below INVENTORY_SIZE
+    pub slot: u32,
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.item_definition().count() > 0 {
+        return;
+    }
+    let items = [
+        ("Dirt", 64, ItemKind::Block(voxel::BLOCK_DIRT)),
+        ("Stone", 64, ItemKind::Block(voxel::BLOCK_STONE)),
+        ("Wood", 64, ItemKind::Block(voxel::BLOCK_WOOD)),
+        ("Iron Ore", 32, ItemKind::Material),
+        ("Iron Ingot", 32, ItemKind::Material),
+        ("Bandage", 10, ItemKind::Food(25.0)),
+    ];
+    for (name, max_stack, kind) in items {
+        ctx.db.item_definition().insert(ItemDefinition {
+            item_id: 0,
+            name: name.to_string(),
+            max_stack,
+            kind,
+        });
+    }
+}
+
+pub fn definition(ctx: &ReducerContext, item_id: u32) -> Result<ItemDefinition, ReducerError> {
+    ctx.db
+        .item_definition()
+        .item_id()
+        .find(item_id)
+        .ok_or_else(|| ReducerError::NotFound("No such item".to_string()))
+}
+
+pub fn item_named(ctx: &ReducerContext, name: &str) -> Option<ItemDefinition> {
+    ctx.db.item_definition().name().find(name.to_string())
+}
+
+fn slot_at(ctx: &ReducerContext, owner: Identity, slot: u32) -> Option<InventorySlot> {
+    ctx.db.inventory_slot().owner().filter(owner).find(|s| s.slot == slot)
+}
+
+fn free_slot(ctx: &ReducerContext, owner: Identity) -> Option<u32> {
+    let used: Vec<u32> = ctx.db.inventory_slot().owner().filter(owner).map(|s| s.slot).collect();
+    (0..INVENTORY_SIZE).find(|slot| !used.contains(slot))
+}
+
+pub fn count(ctx: &ReducerContext, owner: Identity, item_id: u32) -> u32 {
+    ctx.db
+        .inventory_slot()
+        .owner()
+        .filter(owner)
+        .filter(|s| s.item_id == item_id)
+        .map(|s| s.quantity)
+        .sum()
+}
+
+// How many more of the item fit into the inventory
+pub fn space_for(ctx: &ReducerContext, owner: Identity, item_id: u32) -> u32 {
+    let Ok(item) = definition(ctx, item_id) else {
+        return 0;
+    };
+    let slots: Vec<InventorySlot> = ctx.db.inventory_slot().owner().filter(owner).collect();
+    let in_stacks: u32 = slots
+        .iter()
+        .filter(|s| s.item_id == item_id)
+        .map(|s| item.max_stack.saturating_sub(s.quantity))
+        .sum();
+    in_stacks + (INVENTORY_SIZE - slots.len() as u32) * item.max_stack
+}
+
+// Add items to existing stacks first, then to free slots. Returns how many did not fit.
+pub fn give(ctx: &ReducerContext, owner: Identity, item_id: u32, quantity: u32) -> u32 {
+    let Ok(item) = definition(ctx, item_id) else {
+        return quantity;
+    };
+    let mut left = quantity;
+    let stacks: Vec<InventorySlot> = ctx
+        .db
+        .inventory_slot()
+        .owner()
+        .filter(owner)
+        .filter(|s| s.item_id == item_id && s.quantity < item.max_stack)
+        .collect();
+    for mut stack in stacks {
+        if left == 0 {
+            break;
+        }
+        let added = left.min(item.max_stack - stack.quantity);
+        stack.quantity += added;
+        left -= added;
+        ctx.db.inventory_slot().slot_id().update(stack);
+    }
+    while left > 0 {
+        let Some(slot) = free_slot(ctx, owner) else {
+            break;
+        };
+        let added = left.min(item.max_stack);
+        ctx.db.inventory_slot().insert(InventorySlot {
+            slot_id: 0,
+            owner,
+            slot,
+            item_id,
+            quantity: added,
+        });
+        left -= added;
+    }
+    left
+}
+
+// Remove items from wherever they are, all or nothing
+pub fn take(ctx: &ReducerContext, owner: Identity, item_id: u32, quantity: u32) -> ReducerResult {
+    if count(ctx, owner, item_id) < quantity {
+        return Err(ReducerError::InvalidInput("Not enough items".to_string()));
+    }
+    let stacks: Vec<InventorySlot> = ctx
+        .db
+        .inventory_slot()
+        .owner()
+        .filter(owner)
+        .filter(|s| s.item_id == item_id)
+        .collect();
+    let mut left = quantity;
+    for stack in stacks {
+        if left == 0 {
+            break;
+        }
+        left -= remove_from_slot(ctx, stack, left);
+    }
+    Ok(())
+}
+
+// Take up to `quantity` out of a slot, clearing it when it runs out. Returns how many were taken.
+fn remove_from_slot(ctx: &ReducerContext, mut stack: InventorySlot, quantity: u32) -> u32 {
+    let removed = quantity.min(stack.quantity);
+    stack.quantity -= removed;
+    if stack.quantity == 0 {
+        ctx.db.inventory_slot().slot_id().delete(stack.slot_id);
+    } else {
+        ctx.db.inventory_slot().slot_id().update(stack);
+    }
+    removed
+}
+
+fn check_slot(slot: u32) -> ReducerResult {
+    if slot >= INVENTORY_SIZE {
+        return Err(ReducerError::InvalidInput("No such slot".to_string()));
+    }
+    Ok(())
+}
+
+fn sender(ctx: &ReducerContext) -> Result<Player, ReducerError> {
+    ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)
+}
+
+fn occupied_slot(ctx: &ReducerContext, slot: u32) -> Result<InventorySlot, ReducerError> {
+    check_slot(slot)?;
+    slot_at(ctx, ctx.sender, slot).ok_or_else(|| ReducerError::InvalidInput("Slot is empty".to_string()))
+}
+
+// Reducer: Move a stack onto another slot, merging same items and swapping different ones
+#[spacetimedb::reducer]
+pub fn move_item(ctx: &ReducerContext, from: u32, to: u32) -> ReducerResult {
+    sender(ctx)?;
+    let mut source = occupied_slot(ctx, from)?;
+    check_slot(to)?;
+    if from == to {
+        return Ok(());
+    }
+    match slot_at(ctx, ctx.sender, to) {
+        None => {
+            source.slot = to;
+            ctx.db.inventory_slot().slot_id().update(source);
+        }
+        Some(mut target) if target.item_id == source.item_id => {
+            let max_stack = definition(ctx, source.item_id)?.max_stack;
+            let moved = source.quantity.min(max_stack.saturating_sub(target.quantity));
+            target.quantity += moved;
+            ctx.db.inventory_slot().slot_id().update(target);
+            remove_from_slot(ctx, source, moved);
+        }
+        Some(mut target) => {
+            target.slot = from;
+            source.slot = to;
+            ctx.db.inventory_slot().slot_id().update(target);
+            ctx.db.inventory_slot().slot_id().update(source);
+        }
+    }
+    Ok(())
+}
+
+// Reducer: Move part of a stack into an empty slot
+#[spacetimedb::reducer]
+pub fn split_item(ctx: &ReducerContext, from: u32, to: u32, quantity: u32) -> ReducerResult {
+    sender(ctx)?;
+    let source = occupied_slot(ctx, from)?;
+    check_slot(to)?;
+    if slot_at(ctx, ctx.sender, to).is_some() {
+        return Err(ReducerError::InvalidInput("Target slot is not empty".to_string()));
+    }
+    if quantity == 0 || quantity >= source.quantity {
+        return Err(ReducerError::InvalidInput("Split must leave items in both slots".to_string()));
+    }
+    let item_id = source.item_id;
+    remove_from_slot(ctx, source, quantity);
+    ctx.db.inventory_slot().insert(InventorySlot {
+        slot_id: 0,
+        owner: ctx.sender,
+        slot: to,
+        item_id,
+        quantity,
+    });
+    Ok(())
+}
+
+// Reducer: Throw items on the ground in front of the player, the way they last moved
+#[spacetimedb::reducer]
+pub fn drop_item(ctx: &ReducerContext, slot: u32, quantity: u32) -> ReducerResult {
+    let player = sender(ctx)?;
+    if combat::is_dead(ctx, player.entity_id) {
+        return Err(ReducerError::Dead);
+    }
+    let stack = occupied_slot(ctx, slot)?;
+    if quantity == 0 || quantity > stack.quantity {
+        return Err(ReducerError::InvalidInput("Invalid quantity".to_string()));
+    }
+    let item_id = stack.item_id;
+    remove_from_slot(ctx, stack, quantity);
+    item_drop::throw_from(ctx, player.entity_id, player.facing, item_id, quantity);
+    Ok(())
+}
+
+// Reducer: Use one item of a slot, only food does anything for now
+#[spacetimedb::reducer]
+pub fn use_item(ctx: &ReducerContext, slot: u32) -> ReducerResult {
+    let player = sender(ctx)?;
+    if combat::is_dead(ctx, player.entity_id) {
+        return Err(ReducerError::Dead);
+    }
+    let stack = occupied_slot(ctx, slot)?;
+    match definition(ctx, stack.item_id)?.kind {
+        ItemKind::Food(heal) => {
+            let mut health = ctx
+                .db
+                .health()
+                .entity_id()
+                .find(player.entity_id)
+                .ok_or_else(|| ReducerError::Internal("Player has no health".to_string()))?;
+            if health.current >= health.max {
+                return Err(ReducerError::InvalidInput("Already at full health".to_string()));
+            }
+            health.current = (health.current + heal).min(health.max);
+            ctx.db.health().entity_id().update(health);
+        }
+        ItemKind::Material | ItemKind::Block(_) => {
+            return Err(ReducerError::InvalidInput("Item cannot be used".to_string()));
+        }
+    }
+    remove_from_slot(ctx, stack, 1);
+    Ok(())
+}
+
+// Reducer: Admin tool to add an item type
+#[spacetimedb::reducer]
+pub fn define_item(ctx: &ReducerContext, name: String, max_stack: u32, kind: ItemKind) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if name.trim().is_empty() || max_stack == 0 {
+        return Err(ReducerError::InvalidInput("Items need a name and a stack size".to_string()));
+    }
+    if item_named(ctx, &name).is_some() {
+        return Err(ReducerError::InvalidInput("Item name is taken".to_string()));
+    }
+    ctx.db.item_definition().insert(ItemDefinition {
+        item_id: 0,
+        name,
+        max_stack,
+        kind,
+    });
+    Ok(())
+}
+
+// Reducer: Admin tool to hand out items
+#[spacetimedb::reducer]
+pub fn give_item(ctx: &ReducerContext, identity: Identity, item_id: u32, quantity: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    definition(ctx, item_id)?;
+    if give(ctx, identity, item_id, quantity) > 0 {
+        return Err(ReducerError::InvalidInput("Inventory is full".to_string()));
+    }
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use std::time::Duration;
+
+use spacetimedb::{Identity, ReducerContext, Table, TimeDuration, Timestamp};
+
+use crate::combat;
+use crate::ecs::{self, entity, transform, EntityKind};
+use crate::inventory;
+use crate::math::DbVector3;
+use crate::player;
+use crate::terrain;
+use crate::PLAYER_RADIUS;
+
+// Players pick up drops whose center is this close to their edge
+pub const PICKUP_RADIUS: f32 = 1.0;
+pub const DROP_LIFETIME: Duration = Duration::from_secs(120);
+// Keeps a dropped stack from flying straight back into the inventory it left
+const PICKUP_DELAY: Duration = Duration::from_secs(1);
+// Drops land this far in front of whoever threw them
+const THROW_DISTANCE: f32 = 1.5;
+
+// Component: a stack of items lying in the world
+#[spacetimedb::table(name = item_drop, public)]
+pub struct ItemDrop {
+    #[primary_key]
+    pub entity_id: u32,
+    pub item_id: u32,
+    pub quantity: u32,
+    pub pickup_radius: f32,
+    pub pickup_after: Timestamp,
+    pub despawn_at: Timestamp,
+}
+
+// Items that did not fit the inventory of an offline player, e.g. from a craft finishing
+// after they left. Handed over on their next connect instead of being lost.
+#[spacetimedb::table(name = pending_item)]
+pub struct PendingItem {
+    #[primary_key]
+    #[auto_inc]
+    pub pending_id: u64,
+    #[index(btree)]
+    pub owner: Identity,
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+// Put a stack of items on the blocks under `position` in the room
+pub fn spawn_drop(ctx: &ReducerContext, room_id: u32, position: DbVector3, item_id: u32, quantity: u32) -> u32 {
+    let ground = terrain::ground_below(ctx, room_id, position).unwrap_or(position.y);
+    let entity_id = ecs::spawn(ctx, EntityKind::ItemDrop, room_id);
+    ctx.db.transform().insert(ecs::Transform {
+        entity_id,
+        position: DbVector3::new(position.x, ground, position.z),
+        rotation: DbVector3::ZERO,
+    });
+    ctx.db.item_drop().insert(ItemDrop {
+        entity_id,
+        item_id,
+        quantity,
+        pickup_radius: PICKUP_RADIUS,
+        pickup_after: ctx.timestamp + TimeDuration::from_micros(PICKUP_DELAY.as_micros() as i64),
+        despawn_at: ctx.timestamp + TimeDuration::from_micros(DROP_LIFETIME.as_micros() as i64),
+    });
+    entity_id
+}
+
+// Drop items in front of an entity, towards `facing`
+pub fn throw_from(ctx: &ReducerContext, entity_id: u32, facing: DbVector3, item_id: u32, quantity: u32) {
+    let (Some(position), Some(room_id)) = (ecs::position_of(ctx, entity_id), ecs::room_of(ctx, entity_id)) else {
+        return;
+    };
+    let facing = DbVector3::new(facing.x, 0.0, facing.z);
+    let offset = if facing.sqr_magnitude() > 0.0 { facing.normalized() * THROW_DISTANCE } else { DbVector3::ZERO };
+    spawn_drop(ctx, room_id, position + offset, item_id, quantity);
+}
+
+// Put items in a player's inventory, whatever does not fit lands at their feet.
+// Offline players have no feet, the rest is kept for them until they are back.
+pub fn give_or_drop(ctx: &ReducerContext, identity: Identity, item_id: u32, quantity: u32) {
+    let left = inventory::give(ctx, identity, item_id, quantity);
+    if left == 0 {
+        return;
+    }
+    match ctx.db.player().identity().find(identity) {
+        Some(player) => throw_from(ctx, player.entity_id, DbVector3::ZERO, item_id, left),
+        None => {
+            ctx.db.pending_item().insert(PendingItem {
+                pending_id: 0,
+                owner: identity,
+                item_id,
+                quantity: left,
+            });
+        }
+    }
+}
+
+// Hand over what was kept for a player while they were offline, once their player row exists
+pub fn deliver_pending(ctx: &ReducerContext, identity: Identity) {
+    let pending: Vec<PendingItem> = ctx.db.pending_item().owner().filter(identity).collect();
+    for item in pending {
+        ctx.db.pending_item().pending_id().delete(item.pending_id);
+        give_or_drop(ctx, identity, item.item_id, item.quantity);
+    }
+}
+
+// System: hand drops to the players of the room standing on them, and clear out old ones
+pub fn pickup_system(ctx: &ReducerContext, room_id: u32) {
+    let drops: Vec<ItemDrop> = ctx
+        .db
+        .entity()
+        .room_id()
+        .filter(room_id)
+        .filter_map(|e| ctx.db.item_drop().entity_id().find(e.entity_id))
+        .collect();
+    for mut drop in drops {
+        if ctx.timestamp >= drop.despawn_at {
+            ecs::despawn(ctx, drop.entity_id);
+            continue;
+        }
+        if ctx.timestamp < drop.pickup_after {
+            continue;
+        }
+        let Some(position) = ecs::position_of(ctx, drop.entity_id) else {
+            continue;
+        };
+        let reach = drop.pickup_radius + PLAYER_RADIUS;
+        let takers = ctx.db.player().room_id().filter(room_id).filter(|p| {
+            !combat::is_dead(ctx, p.entity_id)
+                && ecs::position_of(ctx, p.entity_id).is_some_and(|at| (at - position).sqr_magnitude() <= reach * reach)
+        });
+        let quantity = drop.quantity;
+        for player in takers {
+            drop.quantity = inventory::give(ctx, player.identity, drop.item_id, drop.quantity);
+            if drop.quantity == 0 {
+                break;
+            }
+        }
+        if drop.quantity == 0 {
+            ecs::despawn(ctx, drop.entity_id);
+        } else if drop.quantity != quantity {
+            ctx.db.item_drop().entity_id().update(drop);
+        }
+    }
+}
+
+pub fn clear(ctx: &ReducerContext, entity_id: u32) {
+    ctx.db.item_drop().entity_id().delete(entity_id);
+}
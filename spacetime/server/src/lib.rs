@@ -1,11 +1,46 @@
 use std::time::Duration;
 pub mod math;
+pub mod admin;
 pub mod command;
+pub mod chat;
+pub mod chunk;
+pub mod combat;
+pub mod crafting;
+pub mod currency;
+pub mod config;
+pub mod ecs;
+pub mod error;
+pub mod gathering;
+pub mod inventory;
+pub mod item_drop;
+pub mod match_state;
+pub mod name;
+pub mod navigation;
+pub mod noise;
+pub mod npc;
+pub mod profile;
+pub mod projectile;
+pub mod rewind;
+pub mod room;
+pub mod spawn;
+pub mod stats;
+pub mod team;
+pub mod terrain;
+pub mod trade;
+pub mod voxel;
+pub mod world_clock;
 
 use math::DbVector3;
 use command::Command;
-use spacetimedb::{Identity, ReducerContext, ScheduleAt, SpacetimeType, Table};
+use ecs::{collider, health, owner, transform, velocity, EntityKind};
+use error::{ReducerError, ReducerResult};
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table, Timestamp};
 
+pub const TICK_INTERVAL: Duration = Duration::from_millis(50);
+// Units per second
+pub const PLAYER_SPEED: f32 = 10.0;
+pub const PLAYER_RADIUS: f32 = 0.5;
+pub const PLAYER_MAX_HEALTH: f32 = 100.0;
 
 
 #[spacetimedb::table(name = player, public)]
@@ -14,10 +49,16 @@ pub struct Player {
     identity: Identity,
     #[auto_inc]
     player_id: u32,
+    // Position, rotation etc. live in the component tables of this entity
+    #[unique]
+    entity_id: u32,
+    #[index(btree)]
+    room_id: u32,
     name: String,
-    position: DbVector3,
-    rotation: DbVector3,
     direction: DbVector3,
+    joined_at: Timestamp,
+    // Last direction the player moved in, unlike `direction` it is kept while standing still
+    facing: DbVector3,
 }
 
 #[spacetimedb::table(name = move_all_players_timer, scheduled(move_all_players))]
@@ -28,45 +69,81 @@ pub struct MoveAllPlayersTimer {
     scheduled_at: spacetimedb::ScheduleAt,
 }
 
+// Commands carry roughly unit length directions, anything else is a broken or cheating client
+fn validated_direction(direction: DbVector3) -> Result<DbVector3, ReducerError> {
+    let dir_mag = direction.magnitude();
+    if !(0.01..=1.1).contains(&dir_mag) {
+        return Err(ReducerError::InvalidInput("Invalid direction magnitude".to_string()));
+    }
+    Ok(direction)
+}
 
 // Reducer: Handle all commands
 #[spacetimedb::reducer]
-pub fn player_command(ctx: &ReducerContext, cmd: Command) -> Result<(), String> {
-    let mut player = ctx.db.player().identity().find(&ctx.sender).unwrap();
+pub fn player_command(ctx: &ReducerContext, cmd: Command) -> ReducerResult {
+    let mut player = ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    if combat::is_dead(ctx, player.entity_id) {
+        return Err(ReducerError::Dead);
+    }
     match cmd {
         Command::Move(move_cmd) => {
-            let dir_mag = move_cmd.direction.magnitude();
-            if dir_mag < 0.01 || dir_mag > 1.1 {
-                return Err("Invalid direction magnitude".to_string());
-            }
-            player.direction = move_cmd.direction.normalized();
+            player.direction = validated_direction(move_cmd.direction)?.normalized();
             ctx.db.player().identity().update(player);
         }
         Command::Jump => {
         }
+        Command::Fire(fire_cmd) => {
+            let direction = validated_direction(fire_cmd.direction)?;
+            combat::use_cooldown(ctx, player.entity_id, projectile::FIRE_COOLDOWN)?;
+            projectile::fire(ctx, player.entity_id, direction);
+        }
+        Command::Attack(attack_cmd) => {
+            let direction = validated_direction(attack_cmd.direction)?;
+            combat::attack(ctx, player.entity_id, attack_cmd.kind, direction, attack_cmd.seen_at)?;
+        }
+        Command::Gather(gather_cmd) => {
+            gathering::gather(ctx, &player, gather_cmd.node_id)?;
+        }
     }
     Ok(())
 }
 
-#[spacetimedb::reducer]
-pub fn move_all_players(ctx: &ReducerContext, _timer: MoveAllPlayersTimer) -> Result<(), String> {
-
-    // Handle player input
-    for player_itr in ctx.db.player().iter() {
-
-        let player = ctx.db.player().identity().find(player_itr.identity);
-        let mut player = player.unwrap();
-
-        let direction = player.direction * 0.5;
-        let new_pos = player.position + direction;
-
-        player.position = new_pos;
-        player.direction = DbVector3 { x: 0.0, y: 0.0, z: 0.0 };
+// System: turn the latest move command of each player into velocity for this tick
+fn player_input_system(ctx: &ReducerContext, room_id: u32) {
+    for mut player in ctx.db.player().room_id().filter(room_id) {
+        if let Some(mut velocity) = ctx.db.velocity().entity_id().find(player.entity_id) {
+            velocity.linear = player.direction * PLAYER_SPEED;
+            ctx.db.velocity().entity_id().update(velocity);
+        }
 
+        if player.direction.sqr_magnitude() > 0.0 {
+            player.facing = player.direction;
+        }
+        player.direction = DbVector3::ZERO;
         ctx.db.player().identity().update(player);
     }
+}
 
-
+// Reducer: World tick, runs every system in order.
+// Kept under its original name so existing databases keep their schedule.
+#[spacetimedb::reducer]
+pub fn move_all_players(ctx: &ReducerContext, _timer: MoveAllPlayersTimer) -> ReducerResult {
+    let dt = TICK_INTERVAL.as_secs_f32();
+
+    world_clock::advance(ctx, dt);
+    for room_id in room::active_rooms(ctx) {
+        player_input_system(ctx, room_id);
+        npc::night_spawn_system(ctx, room_id);
+        npc::npc_system(ctx, room_id);
+        navigation::path_following_system(ctx, room_id);
+        projectile::projectile_system(ctx, room_id, dt);
+        ecs::movement_system(ctx, room_id, dt);
+        item_drop::pickup_system(ctx, room_id);
+        terrain::ground_system(ctx, room_id);
+        chunk::chunk_system(ctx, room_id);
+        rewind::history_system(ctx, room_id);
+        match_state::match_system(ctx, room_id);
+    }
 
     Ok(())
 }
@@ -77,16 +154,28 @@ pub fn init(ctx: &ReducerContext) -> Result<(), String>{
     .move_all_players_timer()
     .try_insert(MoveAllPlayersTimer {
         scheduled_id: 0,
-        scheduled_at: ScheduleAt::Interval(Duration::from_millis(50).into()),
+        scheduled_at: ScheduleAt::Interval(TICK_INTERVAL.into()),
     })?;
+    config::seed(ctx);
+    spawn::seed(ctx);
+    room::seed(ctx);
+    stats::seed(ctx);
+    inventory::seed(ctx);
+    crafting::seed(ctx);
+    gathering::seed(ctx);
+    world_clock::seed(ctx);
+    name::seed_blocklist(ctx);
+    admin::seed_owner(ctx);
     Ok(())
 }
 
 
 
 #[spacetimedb::reducer(client_connected)]
-pub fn identity_connected(ctx: &ReducerContext)  -> Result<(), String> {
+pub fn identity_connected(ctx: &ReducerContext)  -> ReducerResult {
     log::info!("Identity connected, {}!", ctx.sender);
+    admin::check_ban(ctx)?;
+    let profile = profile::load_or_create(ctx);
     if let Some(player) = ctx.db.player().identity().find(ctx.sender)
     {
         log::info!("Player FOUND", );
@@ -94,15 +183,25 @@ pub fn identity_connected(ctx: &ReducerContext)  -> Result<(), String> {
        _ = player;
     }
     else {
-        log::info!("+ Player INSERT", );
+        log::info!("+ Player INSERT, resuming {}", profile.name);
+        // Every session starts in the lobby
+        let room_id = room::lobby(ctx);
+        let entity_id = spawn_player_entity(ctx, room_id, profile.last_position, profile.last_rotation);
+        team::assign(ctx, entity_id, room_id, None)?;
+        if let Some(death) = profile.death {
+            combat::resume_death(ctx, entity_id, death);
+        }
         let _ = ctx.db.player().insert(Player{
         identity: ctx.sender,
         player_id: 0,
-        name: "Lucas".to_string(),
-        position: DbVector3 { x: 0.0, y: 0.0, z: 0.0 },
-        rotation: DbVector3 { x: 0.0, y: 0.0, z: 0.0 },
-        direction: DbVector3 { x: 0.0, y: 0.0, z: 0.0 },
+        entity_id,
+        room_id,
+        name: profile.name,
+        direction: DbVector3::ZERO,
+        joined_at: ctx.timestamp,
+        facing: DbVector3::ZERO,
 });
+        item_drop::deliver_pending(ctx, ctx.sender);
     }
     log::info!("Player tot: , {}!", ctx.db.player().count());
     Ok(())
@@ -114,8 +213,29 @@ pub fn identity_disconnected(ctx: &ReducerContext) {
     if let Some(player) = ctx.db.player().identity().find(ctx.sender)
     {
         log::info!("Identity Disconnected, {}!", ctx.sender);
-        ctx.db.player().delete(player);
+        remove_player(ctx, player);
         log::info!("Player tot: , {}!", ctx.db.player().count());
     }
 }
 
+// Create the entity backing a player of the sender
+fn spawn_player_entity(ctx: &ReducerContext, room_id: u32, position: DbVector3, rotation: DbVector3) -> u32 {
+    let entity_id = ecs::spawn(ctx, EntityKind::Player, room_id);
+    ctx.db.transform().insert(ecs::Transform { entity_id, position, rotation });
+    ctx.db.velocity().insert(ecs::Velocity { entity_id, linear: DbVector3::ZERO });
+    ctx.db.collider().insert(ecs::Collider { entity_id, radius: PLAYER_RADIUS });
+    ctx.db.health().insert(ecs::Health { entity_id, current: PLAYER_MAX_HEALTH, max: PLAYER_MAX_HEALTH });
+    ctx.db.owner().insert(ecs::Owner { entity_id, identity: ctx.sender });
+    entity_id
+}
+
+// End a player's session: save it to their profile, then remove the row and its entity
+pub fn remove_player(ctx: &ReducerContext, player: Player) {
+    profile::save_session(ctx, &player);
+    trade::cancel_all(ctx, player.identity);
+    ecs::despawn(ctx, player.entity_id);
+    let room_id = player.room_id;
+    ctx.db.player().delete(player);
+    room::close_if_empty(ctx, room_id);
+}
+
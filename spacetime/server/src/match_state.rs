@@ -0,0 +1,185 @@
+use spacetimedb::{ReducerContext, ScheduleAt, SpacetimeType, Table, TimeDuration, Timestamp};
+
+use crate::admin;
+use crate::combat::dead;
+use crate::config;
+use crate::ecs::{self, health, velocity};
+use crate::error::{ReducerError, ReducerResult};
+use crate::math::DbVector3;
+use crate::player;
+use crate::spawn;
+use crate::stats;
+use crate::team::{self, team as _};
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum MatchPhase {
+    Warmup,     // Players gather, kills do not score
+    InProgress, // Kills score until a team wins or time runs out
+    Ended,      // Results are shown before the next warmup
+}
+
+// The current match of a room, one row per room
+#[spacetimedb::table(name = match_state, public)]
+pub struct MatchState {
+    #[primary_key]
+    pub room_id: u32,
+    // Increases with every new match
+    pub match_id: u32,
+    pub phase: MatchPhase,
+    pub phase_started_at: Timestamp,
+    pub phase_ends_at: Timestamp,
+    // Set when the match ended with a single best team
+    pub winner: Option<u32>,
+}
+
+// Ends `phase` of `match_id`, ignored if the match has moved on since
+#[spacetimedb::table(name = match_timer, scheduled(advance_match))]
+pub struct MatchTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+    room_id: u32,
+    match_id: u32,
+    phase: MatchPhase,
+}
+
+// Begin the first warmup of a new room
+pub fn start(ctx: &ReducerContext, room_id: u32) {
+    if current(ctx, room_id).is_none() {
+        enter_phase(ctx, room_id, 1, MatchPhase::Warmup, None);
+    }
+}
+
+pub fn current(ctx: &ReducerContext, room_id: u32) -> Option<MatchState> {
+    ctx.db.match_state().room_id().find(room_id)
+}
+
+// Only kills during a running match count towards scores
+pub fn is_live(ctx: &ReducerContext, room_id: u32) -> bool {
+    current(ctx, room_id).is_some_and(|state| state.phase == MatchPhase::InProgress)
+}
+
+fn phase_duration(ctx: &ReducerContext, phase: MatchPhase) -> TimeDuration {
+    let config = config::get(ctx);
+    let secs = match phase {
+        MatchPhase::Warmup => config.warmup_secs,
+        MatchPhase::InProgress => config.match_secs,
+        MatchPhase::Ended => config.results_secs,
+    };
+    TimeDuration::from_micros(secs as i64 * 1_000_000)
+}
+
+fn enter_phase(ctx: &ReducerContext, room_id: u32, match_id: u32, phase: MatchPhase, winner: Option<u32>) {
+    log::info!("Match {} of room {} enters {:?}", match_id, room_id, phase);
+    let ends_at = ctx.timestamp + phase_duration(ctx, phase);
+    let state = MatchState {
+        room_id,
+        match_id,
+        phase,
+        phase_started_at: ctx.timestamp,
+        phase_ends_at: ends_at,
+        winner,
+    };
+    if current(ctx, room_id).is_some() {
+        ctx.db.match_state().room_id().update(state);
+    } else {
+        ctx.db.match_state().insert(state);
+    }
+    ctx.db.match_timer().insert(MatchTimer {
+        scheduled_id: 0,
+        scheduled_at: ScheduleAt::Time(ends_at),
+        room_id,
+        match_id,
+        phase,
+    });
+}
+
+// Team of the room with the strictly highest score, None on a tie
+fn leading_team(ctx: &ReducerContext, room_id: u32) -> Option<u32> {
+    let mut teams: Vec<_> = ctx.db.team().room_id().filter(room_id).map(|t| (t.team_id, t.score)).collect();
+    teams.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+    match teams.as_slice() {
+        [(first, a), (_, b), ..] if a > b => Some(*first),
+        [(first, _)] => Some(*first),
+        _ => None,
+    }
+}
+
+// Bring everyone in the room back to life at a spawn point of their team
+fn reset_players(ctx: &ReducerContext, room_id: u32) {
+    for player in ctx.db.player().room_id().filter(room_id) {
+        let entity_id = player.entity_id;
+        // Pending respawn timers find no matching dead row and do nothing, see `combat::respawn`
+        ctx.db.dead().entity_id().delete(entity_id);
+        if let Some(mut health) = ctx.db.health().entity_id().find(entity_id) {
+            health.current = health.max;
+            ctx.db.health().entity_id().update(health);
+        }
+        if let Some(mut velocity) = ctx.db.velocity().entity_id().find(entity_id) {
+            velocity.linear = DbVector3::ZERO;
+            ctx.db.velocity().entity_id().update(velocity);
+        }
+        let spawn = spawn::select(ctx, room_id, team::team_of(ctx, entity_id));
+        ecs::set_transform(ctx, entity_id, spawn.position, spawn.rotation);
+    }
+}
+
+fn next_phase(ctx: &ReducerContext, state: MatchState) {
+    match state.phase {
+        MatchPhase::Warmup => {
+            team::reset_scores(ctx, state.room_id);
+            reset_players(ctx, state.room_id);
+            stats::reset_scoreboard(ctx, state.room_id, state.match_id);
+            enter_phase(ctx, state.room_id, state.match_id, MatchPhase::InProgress, None);
+        }
+        MatchPhase::InProgress => {
+            let winner = leading_team(ctx, state.room_id);
+            log::info!("Match {} of room {} won by team {:?}", state.match_id, state.room_id, winner);
+            if let Some(team_id) = winner {
+                stats::award_win(ctx, state.room_id, team_id);
+            }
+            enter_phase(ctx, state.room_id, state.match_id, MatchPhase::Ended, winner);
+        }
+        MatchPhase::Ended => {
+            reset_players(ctx, state.room_id);
+            enter_phase(ctx, state.room_id, state.match_id + 1, MatchPhase::Warmup, None);
+        }
+    }
+}
+
+// System: end the room's match as soon as a team reaches the score limit
+pub fn match_system(ctx: &ReducerContext, room_id: u32) {
+    let Some(state) = current(ctx, room_id) else {
+        return;
+    };
+    if state.phase != MatchPhase::InProgress {
+        return;
+    }
+    let score_limit = config::get(ctx).score_limit;
+    if score_limit > 0 && ctx.db.team().room_id().filter(room_id).any(|t| t.score >= score_limit) {
+        next_phase(ctx, state);
+    }
+}
+
+// Reducer: Scheduled, move the match on when a phase runs out of time
+#[spacetimedb::reducer]
+pub fn advance_match(ctx: &ReducerContext, timer: MatchTimer) -> ReducerResult {
+    if ctx.sender != ctx.identity() {
+        return Err(ReducerError::PermissionDenied("Match phases are scheduled by the server".to_string()));
+    }
+    match current(ctx, timer.room_id) {
+        Some(state) if state.match_id == timer.match_id && state.phase == timer.phase => next_phase(ctx, state),
+        _ => {}
+    }
+    Ok(())
+}
+
+// Reducer: Admin tool to end the current phase of a room right away
+#[spacetimedb::reducer]
+pub fn skip_match_phase(ctx: &ReducerContext, room_id: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    let state = current(ctx, room_id).ok_or_else(|| ReducerError::NotFound("No such room".to_string()))?;
+    next_phase(ctx, state);
+    Ok(())
+}
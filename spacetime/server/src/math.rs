@@ -111,6 +111,8 @@ impl std::ops::Div<f32> for DbVector3 {
 }
 
 impl DbVector3 {
+    pub const ZERO: DbVector3 = DbVector3 { x: 0.0, y: 0.0, z: 0.0 };
+
     pub fn new(x: f32, y: f32, z: f32) -> Self {
         Self { x, y, z }
     }
@@ -126,4 +128,24 @@ impl DbVector3 {
     pub fn normalized(self) -> DbVector3 {
         self / self.magnitude()
     }
+
+    pub fn dot(&self, other: &DbVector3) -> f32 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+}
+
+// Closest point to `point` on the segment from `start` to `end`
+pub fn closest_point_on_segment(start: DbVector3, end: DbVector3, point: DbVector3) -> DbVector3 {
+    let segment = end - start;
+    let len_sq = segment.sqr_magnitude();
+    if len_sq == 0.0 {
+        return start;
+    }
+    let t = ((point - start).dot(&segment) / len_sq).clamp(0.0, 1.0);
+    start + segment * t
+}
+
+// Does a sphere moving from `start` to `end` touch a sphere at `center`, `radius` being their summed radii
+pub fn segment_hits_sphere(start: DbVector3, end: DbVector3, center: DbVector3, radius: f32) -> bool {
+    (closest_point_on_segment(start, end, center) - center).sqr_magnitude() <= radius * radius
 }
\ No newline at end of file
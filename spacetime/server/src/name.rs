@@ -0,0 +1,121 @@
+use spacetimedb::{Identity, ReducerContext, Table};
+
+use crate::error::{ReducerError, ReducerResult};
+use crate::player;
+use crate::profile::player_profile;
+
+pub const MIN_NAME_LEN: usize = 3;
+pub const MAX_NAME_LEN: usize = 16;
+
+// Seeded into `name_blocklist` at init, more can be added to the table later
+const DEFAULT_BLOCKED_WORDS: &[&str] = &["admin", "moderator", "fuck", "shit", "cunt", "nigger", "faggot"];
+
+// Words that may not be one of the words of a player name (matched case-insensitively), see `name_words`
+#[spacetimedb::table(name = name_blocklist)]
+pub struct NameBlocklist {
+    #[primary_key]
+    word: String,
+}
+
+pub fn seed_blocklist(ctx: &ReducerContext) {
+    for word in DEFAULT_BLOCKED_WORDS {
+        if ctx.db.name_blocklist().word().find(word.to_string()).is_none() {
+            ctx.db.name_blocklist().insert(NameBlocklist { word: word.to_string() });
+        }
+    }
+}
+
+// Name given to players that have not picked one yet
+pub fn default_name(identity: Identity) -> String {
+    format!("Player-{}", identity.to_abbreviated_hex())
+}
+
+// Lowercase words of a name, split at '_', '-', digits and where lower case turns upper case.
+// Blocked words only count as whole words, so "Badminton" is fine while "Super_Admin" and "SuperAdmin" are not.
+fn name_words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut after_lower = false;
+    for c in name.chars() {
+        let boundary = !c.is_ascii_alphabetic() || (c.is_ascii_uppercase() && after_lower);
+        if boundary && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+        }
+        if c.is_ascii_alphabetic() {
+            current.push(c.to_ascii_lowercase());
+        }
+        after_lower = c.is_ascii_lowercase();
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+pub fn validate_name(ctx: &ReducerContext, name: &str) -> ReducerResult {
+    let len = name.chars().count();
+    if !(MIN_NAME_LEN..=MAX_NAME_LEN).contains(&len) {
+        return Err(ReducerError::InvalidInput(format!(
+            "Name must be {}-{} characters",
+            MIN_NAME_LEN, MAX_NAME_LEN
+        )));
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(ReducerError::InvalidInput(
+            "Name may only contain letters, digits, '_' and '-'".to_string(),
+        ));
+    }
+
+    let words = name_words(name);
+    if ctx.db.name_blocklist().iter().any(|blocked| words.contains(&blocked.word.to_ascii_lowercase())) {
+        return Err(ReducerError::InvalidInput("Name is not allowed".to_string()));
+    }
+    if ctx
+        .db
+        .player_profile()
+        .iter()
+        .any(|p| p.identity != ctx.sender && p.name.eq_ignore_ascii_case(name))
+    {
+        return Err(ReducerError::InvalidInput("Name is already taken".to_string()));
+    }
+    Ok(())
+}
+
+// Reducer: Rename the calling player
+#[spacetimedb::reducer]
+pub fn set_name(ctx: &ReducerContext, name: String) -> ReducerResult {
+    let name = name.trim().to_string();
+    let mut player = ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    validate_name(ctx, &name)?;
+
+    log::info!("{} renamed {} -> {}", ctx.sender, player.name, name);
+    if let Some(mut profile) = ctx.db.player_profile().identity().find(ctx.sender) {
+        profile.name = name.clone();
+        ctx.db.player_profile().identity().update(profile);
+    }
+    player.name = name;
+    ctx.db.player().identity().update(player);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn splits_names_into_words() {
+        assert_eq!(name_words("SuperAdmin"), ["super", "admin"]);
+        assert_eq!(name_words("the_admin-42x"), ["the", "admin", "x"]);
+        assert_eq!(name_words("ADMIN"), ["admin"]);
+    }
+
+    #[test]
+    fn blocked_words_only_match_whole_words() {
+        let blocked = |name: &str| name_words(name).iter().any(|w| DEFAULT_BLOCKED_WORDS.contains(&w.as_str()));
+        assert!(!blocked("Badminton"));
+        assert!(!blocked("Shitake_Fan"));
+        assert!(blocked("Super_Admin"));
+        assert!(blocked("SuperAdmin"));
+        assert!(blocked("admin7"));
+    }
+}
@@ -0,0 +1,300 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::time::Duration;
+
+use spacetimedb::{ReducerContext, Table, TimeDuration, Timestamp};
+
+use crate::admin;
+use crate::ecs::{self, entity, velocity};
+use crate::error::{ReducerError, ReducerResult};
+use crate::math::DbVector3;
+
+// The walkable area is a square grid centered on the origin, on the ground plane
+pub const NAV_CELL_SIZE: f32 = 1.0;
+pub const NAV_GRID_SIZE: i32 = 128;
+const NAV_HALF_EXTENT: f32 = NAV_GRID_SIZE as f32 * NAV_CELL_SIZE / 2.0;
+// Colliders are grown by this much so agents do not clip corners
+const AGENT_RADIUS: f32 = 0.5;
+// Give up on searches that expand more cells than this
+const MAX_SEARCH_CELLS: usize = 4096;
+// Keep following the current path while the goal stays this close to where it was planned
+const REPLAN_DISTANCE: f32 = 1.0;
+// Paths towards a moving goal are refreshed at most this often
+const REPLAN_INTERVAL: Duration = Duration::from_millis(500);
+const ARRIVE_DISTANCE: f32 = 0.3;
+const STRAIGHT_COST: u32 = 10;
+const DIAGONAL_COST: u32 = 14;
+
+type Cell = (i32, i32);
+
+// Axis aligned box the world is built from. Shared by every room, like spawn points.
+#[spacetimedb::table(name = static_collider, public)]
+pub struct StaticCollider {
+    #[primary_key]
+    #[auto_inc]
+    pub collider_id: u32,
+    pub center: DbVector3,
+    pub half_extents: DbVector3,
+}
+
+// Grid cells agents cannot stand in, derived from `static_collider`
+#[spacetimedb::table(name = nav_blocked_cell)]
+pub struct NavBlockedCell {
+    #[primary_key]
+    cell_id: u32,
+}
+
+// Component: the path an entity is walking, turned into velocity by `path_following_system`
+#[spacetimedb::table(name = nav_path)]
+pub struct NavPath {
+    #[primary_key]
+    entity_id: u32,
+    // Where the path was planned to
+    goal: DbVector3,
+    // Just the goal when no path was found, the entity then heads straight for it
+    waypoints: Vec<DbVector3>,
+    // Whether the waypoints came from a search that failed
+    direct: bool,
+    next: u32,
+    speed: f32,
+    planned_at: Timestamp,
+}
+
+fn cell_of(position: DbVector3) -> Option<Cell> {
+    let x = ((position.x + NAV_HALF_EXTENT) / NAV_CELL_SIZE).floor() as i32;
+    let z = ((position.z + NAV_HALF_EXTENT) / NAV_CELL_SIZE).floor() as i32;
+    in_bounds((x, z)).then_some((x, z))
+}
+
+fn in_bounds((x, z): Cell) -> bool {
+    (0..NAV_GRID_SIZE).contains(&x) && (0..NAV_GRID_SIZE).contains(&z)
+}
+
+fn cell_id((x, z): Cell) -> u32 {
+    (z * NAV_GRID_SIZE + x) as u32
+}
+
+fn cell_center((x, z): Cell, y: f32) -> DbVector3 {
+    DbVector3::new(
+        (x as f32 + 0.5) * NAV_CELL_SIZE - NAV_HALF_EXTENT,
+        y,
+        (z as f32 + 0.5) * NAV_CELL_SIZE - NAV_HALF_EXTENT,
+    )
+}
+
+// Octile distance, exact on an empty 8-connected grid
+fn heuristic(a: Cell, b: Cell) -> u32 {
+    let dx = (a.0 - b.0).unsigned_abs();
+    let dz = (a.1 - b.1).unsigned_abs();
+    STRAIGHT_COST * dx.max(dz) + (DIAGONAL_COST - STRAIGHT_COST) * dx.min(dz)
+}
+
+// Mark every cell whose center lies inside a (grown) static collider
+pub fn rebuild_grid(ctx: &ReducerContext) {
+    let old: Vec<NavBlockedCell> = ctx.db.nav_blocked_cell().iter().collect();
+    for cell in old {
+        ctx.db.nav_blocked_cell().delete(cell);
+    }
+
+    let mut blocked = HashSet::new();
+    for collider in ctx.db.static_collider().iter() {
+        let min_x = collider.center.x - collider.half_extents.x - AGENT_RADIUS;
+        let max_x = collider.center.x + collider.half_extents.x + AGENT_RADIUS;
+        let min_z = collider.center.z - collider.half_extents.z - AGENT_RADIUS;
+        let max_z = collider.center.z + collider.half_extents.z + AGENT_RADIUS;
+        let first = |min: f32| (((min + NAV_HALF_EXTENT) / NAV_CELL_SIZE).floor() as i32).max(0);
+        let last = |max: f32| (((max + NAV_HALF_EXTENT) / NAV_CELL_SIZE).floor() as i32).min(NAV_GRID_SIZE - 1);
+        for z in first(min_z)..=last(max_z) {
+            for x in first(min_x)..=last(max_x) {
+                let center = cell_center((x, z), 0.0);
+                if (min_x..=max_x).contains(&center.x) && (min_z..=max_z).contains(&center.z) {
+                    blocked.insert(cell_id((x, z)));
+                }
+            }
+        }
+    }
+    for cell_id in blocked {
+        ctx.db.nav_blocked_cell().insert(NavBlockedCell { cell_id });
+    }
+    // Existing paths may walk through the new geometry
+    let paths: Vec<NavPath> = ctx.db.nav_path().iter().collect();
+    for path in paths {
+        ctx.db.nav_path().delete(path);
+    }
+}
+
+// A* over the nav grid. Returns the points to walk through after `from`, ending at `to`,
+// or None if `to` cannot be reached.
+pub fn find_path(ctx: &ReducerContext, from: DbVector3, to: DbVector3) -> Option<Vec<DbVector3>> {
+    let start = cell_of(from)?;
+    let goal = cell_of(to)?;
+    let blocked: HashSet<u32> = ctx.db.nav_blocked_cell().iter().map(|c| c.cell_id).collect();
+    let is_blocked = |cell: Cell| !in_bounds(cell) || blocked.contains(&cell_id(cell));
+    if is_blocked(goal) {
+        return None;
+    }
+
+    let mut open = BinaryHeap::new();
+    let mut best_cost: HashMap<Cell, u32> = HashMap::new();
+    let mut came_from: HashMap<Cell, Cell> = HashMap::new();
+    open.push(Reverse((heuristic(start, goal), 0, start)));
+    best_cost.insert(start, 0);
+
+    let mut expanded = 0;
+    while let Some(Reverse((_, cost, cell))) = open.pop() {
+        if cell == goal {
+            let mut cells = vec![cell];
+            let mut current = cell;
+            while let Some(&previous) = came_from.get(&current) {
+                cells.push(previous);
+                current = previous;
+            }
+            cells.pop(); // The start cell, we are already in it
+            cells.reverse();
+            let mut path: Vec<DbVector3> = cells.into_iter().map(|c| cell_center(c, to.y)).collect();
+            path.pop();
+            path.push(to);
+            return Some(path);
+        }
+        if best_cost.get(&cell).is_some_and(|&best| cost > best) {
+            continue;
+        }
+        expanded += 1;
+        if expanded > MAX_SEARCH_CELLS {
+            return None;
+        }
+
+        for dz in -1..=1 {
+            for dx in -1..=1 {
+                if dx == 0 && dz == 0 {
+                    continue;
+                }
+                let next = (cell.0 + dx, cell.1 + dz);
+                if is_blocked(next) {
+                    continue;
+                }
+                let diagonal = dx != 0 && dz != 0;
+                // No cutting corners past a blocked cell
+                if diagonal && (is_blocked((cell.0 + dx, cell.1)) || is_blocked((cell.0, cell.1 + dz))) {
+                    continue;
+                }
+                let next_cost = cost + if diagonal { DIAGONAL_COST } else { STRAIGHT_COST };
+                if best_cost.get(&next).is_none_or(|&best| next_cost < best) {
+                    best_cost.insert(next, next_cost);
+                    came_from.insert(next, cell);
+                    open.push(Reverse((next_cost + heuristic(next, goal), next_cost, next)));
+                }
+            }
+        }
+    }
+    None
+}
+
+// Walk the entity towards `goal` at `speed`, planning a new path only when needed
+pub fn set_goal(ctx: &ReducerContext, entity_id: u32, goal: DbVector3, speed: f32) {
+    let existing = ctx.db.nav_path().entity_id().find(entity_id);
+    if let Some(path) = &existing {
+        if (path.goal - goal).sqr_magnitude() <= REPLAN_DISTANCE * REPLAN_DISTANCE {
+            return;
+        }
+        // A goal that keeps moving only gets a new path every so often,
+        // and a search that failed is not retried right away either
+        let walking = (path.next as usize) < path.waypoints.len();
+        let replan_at = path.planned_at + TimeDuration::from_micros(REPLAN_INTERVAL.as_micros() as i64);
+        if (walking || path.direct) && ctx.timestamp < replan_at {
+            return;
+        }
+    }
+    let Some(position) = ecs::position_of(ctx, entity_id) else {
+        return;
+    };
+    // Off the grid or walled off, steer straight at the goal like before there was a grid
+    let (waypoints, direct) = match find_path(ctx, position, goal) {
+        Some(waypoints) => (waypoints, false),
+        None => (vec![goal], true),
+    };
+    let path = NavPath {
+        entity_id,
+        goal,
+        waypoints,
+        direct,
+        next: 0,
+        speed,
+        planned_at: ctx.timestamp,
+    };
+    if existing.is_some() {
+        ctx.db.nav_path().entity_id().update(path);
+    } else {
+        ctx.db.nav_path().insert(path);
+    }
+}
+
+pub fn stop(ctx: &ReducerContext, entity_id: u32) {
+    if ctx.db.nav_path().entity_id().delete(entity_id) {
+        if let Some(mut velocity) = ctx.db.velocity().entity_id().find(entity_id) {
+            velocity.linear = DbVector3::ZERO;
+            ctx.db.velocity().entity_id().update(velocity);
+        }
+    }
+}
+
+// System: steer every entity of the room with a path towards its next waypoint
+pub fn path_following_system(ctx: &ReducerContext, room_id: u32) {
+    let paths: Vec<NavPath> = ctx
+        .db
+        .entity()
+        .room_id()
+        .filter(room_id)
+        .filter_map(|e| ctx.db.nav_path().entity_id().find(e.entity_id))
+        .collect();
+    for mut path in paths {
+        let (Some(position), Some(mut velocity)) = (
+            ecs::position_of(ctx, path.entity_id),
+            ctx.db.velocity().entity_id().find(path.entity_id),
+        ) else {
+            continue;
+        };
+        let mut direction = None;
+        while let Some(&waypoint) = path.waypoints.get(path.next as usize) {
+            let delta = DbVector3::new(waypoint.x - position.x, 0.0, waypoint.z - position.z);
+            if delta.magnitude() > ARRIVE_DISTANCE {
+                direction = Some(delta.normalized());
+                break;
+            }
+            path.next += 1;
+        }
+        velocity.linear = direction.map_or(DbVector3::ZERO, |d| d * path.speed);
+        ctx.db.velocity().entity_id().update(velocity);
+        ctx.db.nav_path().entity_id().update(path);
+    }
+}
+
+pub fn clear(ctx: &ReducerContext, entity_id: u32) {
+    ctx.db.nav_path().entity_id().delete(entity_id);
+}
+
+// Reducer: Admin tool to add world geometry, the nav grid follows
+#[spacetimedb::reducer]
+pub fn add_static_collider(ctx: &ReducerContext, center: DbVector3, half_extents: DbVector3) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if half_extents.x <= 0.0 || half_extents.y <= 0.0 || half_extents.z <= 0.0 {
+        return Err(ReducerError::InvalidInput("Half extents must be positive".to_string()));
+    }
+    ctx.db.static_collider().insert(StaticCollider {
+        collider_id: 0,
+        center,
+        half_extents,
+    });
+    rebuild_grid(ctx);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_static_collider(ctx: &ReducerContext, collider_id: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if !ctx.db.static_collider().collider_id().delete(collider_id) {
+        return Err(ReducerError::NotFound("No such collider".to_string()));
+    }
+    rebuild_grid(ctx);
+    Ok(())
+}
@@ -0,0 +1,70 @@
+// Deterministic terrain noise. Plain Rust with no SpacetimeDB types so the client
+// library can build this very file and get the same heights from the same seed.
+
+// Highest the terrain gets above y = 0, and how deep it goes below
+pub const TERRAIN_AMPLITUDE: f32 = 8.0;
+// Size of the largest hills, in world units
+const BASE_WAVELENGTH: f32 = 64.0;
+const OCTAVES: u32 = 4;
+// Each octave has double the frequency and this much of the amplitude
+const PERSISTENCE: f32 = 0.5;
+
+// splitmix64 finalizer over the seed and lattice point, mapped to [0, 1)
+fn lattice(seed: u64, x: i32, z: i32) -> f32 {
+    let mut h = seed ^ ((x as u32 as u64) << 32 | z as u32 as u64);
+    h = h.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    h = (h ^ (h >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    h = (h ^ (h >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    h ^= h >> 31;
+    (h >> 40) as f32 / (1u64 << 24) as f32
+}
+
+fn smoothstep(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+// Value noise in [0, 1), smoothly interpolated between integer lattice points
+fn value_noise(seed: u64, x: f32, z: f32) -> f32 {
+    let (x0, z0) = (x.floor(), z.floor());
+    let (tx, tz) = (smoothstep(x - x0), smoothstep(z - z0));
+    let (xi, zi) = (x0 as i32, z0 as i32);
+    let a = lattice(seed, xi, zi);
+    let b = lattice(seed, xi + 1, zi);
+    let c = lattice(seed, xi, zi + 1);
+    let d = lattice(seed, xi + 1, zi + 1);
+    let top = a + (b - a) * tx;
+    let bottom = c + (d - c) * tx;
+    top + (bottom - top) * tz
+}
+
+// Ground height at (x, z) for a world generated from `seed`
+pub fn height(seed: u64, x: f32, z: f32) -> f32 {
+    let mut frequency = 1.0 / BASE_WAVELENGTH;
+    let mut amplitude = 1.0;
+    let mut total = 0.0;
+    let mut max = 0.0;
+    for octave in 0..OCTAVES {
+        // Every octave gets its own lattice so they do not line up
+        let octave_seed = seed.wrapping_add(octave as u64);
+        total += value_noise(octave_seed, x * frequency, z * frequency) * amplitude;
+        max += amplitude;
+        frequency *= 2.0;
+        amplitude *= PERSISTENCE;
+    }
+    (total / max * 2.0 - 1.0) * TERRAIN_AMPLITUDE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn height_is_deterministic_per_seed() {
+        for (x, z) in [(0.0, 0.0), (12.5, -40.25), (-300.0, 77.7)] {
+            assert_eq!(height(42, x, z), height(42, x, z));
+            assert!(height(42, x, z).abs() <= TERRAIN_AMPLITUDE);
+        }
+        let samples = |seed| (0..16).map(|i| height(seed, i as f32 * 9.0, 3.0)).collect::<Vec<_>>();
+        assert_ne!(samples(1), samples(2));
+    }
+}
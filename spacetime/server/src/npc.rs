@@ -0,0 +1,313 @@
+use std::time::Duration;
+
+use spacetimedb::rand::Rng;
+use spacetimedb::{ReducerContext, SpacetimeType, Table, TimeDuration, Timestamp};
+
+use crate::admin;
+use crate::combat::{self, DamageSource};
+use crate::ecs::{self, collider, entity, health, transform, velocity, EntityKind};
+use crate::error::{ReducerError, ReducerResult};
+use crate::math::DbVector3;
+use crate::navigation;
+use crate::player;
+use crate::room::room;
+use crate::terrain;
+use crate::world_clock;
+
+pub const NPC_RADIUS: f32 = 0.5;
+pub const NPC_MAX_HEALTH: f32 = 50.0;
+// Units per second
+const NPC_SPEED: f32 = 4.0;
+const DEFAULT_SENSE_RADIUS: f32 = 12.0;
+const WANDER_RADIUS: f32 = 8.0;
+const WANDER_PAUSE: Duration = Duration::from_secs(2);
+// Close enough to a destination to count as there
+const ARRIVE_DISTANCE: f32 = 0.3;
+// How far ahead of a threat fleeing NPCs aim
+const FLEE_DISTANCE: f32 = 5.0;
+const ATTACK_REACH: f32 = 1.2;
+const ATTACK_DAMAGE: f32 = 10.0;
+const ATTACK_COOLDOWN: Duration = Duration::from_secs(1);
+// Hostile NPCs that roam rooms at night and are gone by sunrise
+const NIGHT_NPC_NAME: &str = "Night Stalker";
+const NIGHT_NPCS_PER_ROOM: usize = 3;
+// Odds per tick of another one showing up while below the cap
+const NIGHT_SPAWN_CHANCE: f64 = 0.01;
+// They appear out of sight, somewhere this far from a player
+const NIGHT_SPAWN_DISTANCE: std::ops::Range<f32> = 20.0..30.0;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum NpcBehavior {
+    Wander, // Roam around home
+    Patrol, // Walk its waypoints in a loop
+    Chase,  // Wander until a player is sensed, then hunt them
+    Flee,   // Wander until a player is sensed, then run away
+}
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum NpcState {
+    Idle,
+    Wandering,
+    Patrolling,
+    Chasing,
+    Fleeing,
+}
+
+// Component: server driven character, moves through its velocity like players do
+#[spacetimedb::table(name = npc, public)]
+pub struct Npc {
+    #[primary_key]
+    pub entity_id: u32,
+    pub name: String,
+    pub behavior: NpcBehavior,
+    pub state: NpcState,
+    // Wandering stays around here
+    pub home: DbVector3,
+    pub sense_radius: f32,
+    // Player entity being chased or fled from
+    pub target: Option<u32>,
+    pub destination: Option<DbVector3>,
+    pub waypoint_index: u32,
+    // Wandering NPCs wait until then before picking a new destination
+    pub next_decision_at: Timestamp,
+    // Brought out by `night_spawn_system`, which clears it again at sunrise
+    pub night_spawn: bool,
+}
+
+#[spacetimedb::table(name = patrol_waypoint, public)]
+pub struct PatrolWaypoint {
+    #[primary_key]
+    #[auto_inc]
+    waypoint_id: u64,
+    #[index(btree)]
+    pub entity_id: u32,
+    // Waypoints are visited in increasing order
+    pub order: u32,
+    pub position: DbVector3,
+}
+
+pub fn clear(ctx: &ReducerContext, entity_id: u32) {
+    ctx.db.npc().entity_id().delete(entity_id);
+    ctx.db.patrol_waypoint().entity_id().delete(entity_id);
+}
+
+// Nearest live player of the room within `radius`
+fn nearest_player(ctx: &ReducerContext, room_id: u32, position: DbVector3, radius: f32) -> Option<(u32, DbVector3)> {
+    ctx.db
+        .player()
+        .room_id()
+        .filter(room_id)
+        .filter(|p| !combat::is_dead(ctx, p.entity_id))
+        .filter_map(|p| Some((p.entity_id, ecs::position_of(ctx, p.entity_id)?)))
+        .filter(|(_, pos)| (*pos - position).sqr_magnitude() <= radius * radius)
+        .min_by(|a, b| {
+            let da = (a.1 - position).sqr_magnitude();
+            let db = (b.1 - position).sqr_magnitude();
+            da.total_cmp(&db)
+        })
+}
+
+// Unit direction from `from` to `to` along the ground, None when already there
+fn ground_direction(from: DbVector3, to: DbVector3) -> Option<DbVector3> {
+    let delta = DbVector3::new(to.x - from.x, 0.0, to.z - from.z);
+    (delta.magnitude() > ARRIVE_DISTANCE).then(|| delta.normalized())
+}
+
+fn waypoints(ctx: &ReducerContext, entity_id: u32) -> Vec<PatrolWaypoint> {
+    let mut points: Vec<PatrolWaypoint> = ctx.db.patrol_waypoint().entity_id().filter(entity_id).collect();
+    points.sort_by_key(|w| w.order);
+    points
+}
+
+fn random_destination(ctx: &ReducerContext, home: DbVector3) -> DbVector3 {
+    let angle = ctx.rng().gen_range(0.0..std::f32::consts::TAU);
+    let distance = ctx.rng().gen_range(0.0..WANDER_RADIUS);
+    DbVector3::new(home.x + angle.cos() * distance, home.y, home.z + angle.sin() * distance)
+}
+
+// Pick the state for this tick from the behavior and what the NPC can sense
+fn think(npc: &mut Npc, sensed: Option<u32>, has_waypoints: bool) {
+    let (state, target) = match (npc.behavior, sensed) {
+        (NpcBehavior::Chase, Some(player)) => (NpcState::Chasing, Some(player)),
+        (NpcBehavior::Flee, Some(player)) => (NpcState::Fleeing, Some(player)),
+        (NpcBehavior::Patrol, _) if has_waypoints => (NpcState::Patrolling, None),
+        (NpcBehavior::Patrol, _) => (NpcState::Idle, None),
+        _ => (NpcState::Wandering, None),
+    };
+    if state != npc.state {
+        npc.destination = None;
+    }
+    npc.state = state;
+    npc.target = target;
+}
+
+// Work out where the NPC wants to go this tick, None to stand still
+fn act(ctx: &ReducerContext, npc: &mut Npc, position: DbVector3) -> Option<DbVector3> {
+    match npc.state {
+        NpcState::Idle => None,
+        NpcState::Chasing => {
+            let target = ecs::position_of(ctx, npc.target?)?;
+            if (target - position).sqr_magnitude() <= ATTACK_REACH * ATTACK_REACH {
+                if combat::use_cooldown(ctx, npc.entity_id, ATTACK_COOLDOWN).is_ok() {
+                    combat::apply_damage(ctx, npc.target?, ATTACK_DAMAGE, DamageSource::Entity(npc.entity_id));
+                }
+                return None;
+            }
+            Some(target)
+        }
+        NpcState::Fleeing => {
+            let threat = ecs::position_of(ctx, npc.target?)?;
+            match ground_direction(threat, position) {
+                Some(away) => Some(position + away * FLEE_DISTANCE),
+                None => Some(npc.home),
+            }
+        }
+        NpcState::Patrolling => {
+            let points = waypoints(ctx, npc.entity_id);
+            if points.is_empty() {
+                return None;
+            }
+            let count = points.len() as u32;
+            npc.waypoint_index %= count;
+            let mut point = &points[npc.waypoint_index as usize];
+            if ground_direction(position, point.position).is_none() {
+                npc.waypoint_index = (npc.waypoint_index + 1) % count;
+                point = &points[npc.waypoint_index as usize];
+            }
+            Some(point.position)
+        }
+        NpcState::Wandering => {
+            if npc.destination.is_none() && ctx.timestamp >= npc.next_decision_at {
+                npc.destination = Some(random_destination(ctx, npc.home));
+            }
+            let destination = npc.destination?;
+            if ground_direction(position, destination).is_none() {
+                npc.destination = None;
+                npc.next_decision_at = ctx.timestamp + TimeDuration::from_micros(WANDER_PAUSE.as_micros() as i64);
+                return None;
+            }
+            Some(destination)
+        }
+    }
+}
+
+// System: run every NPC of the room through its state machine and hand its goal to navigation
+pub fn npc_system(ctx: &ReducerContext, room_id: u32) {
+    let npcs: Vec<Npc> = ctx
+        .db
+        .entity()
+        .room_id()
+        .filter(room_id)
+        .filter(|e| e.kind == EntityKind::Npc)
+        .filter_map(|e| ctx.db.npc().entity_id().find(e.entity_id))
+        .collect();
+    for mut npc in npcs {
+        let Some(position) = ecs::position_of(ctx, npc.entity_id) else {
+            continue;
+        };
+        let sensed = nearest_player(ctx, room_id, position, npc.sense_radius).map(|(id, _)| id);
+        let has_waypoints = ctx.db.patrol_waypoint().entity_id().filter(npc.entity_id).next().is_some();
+        think(&mut npc, sensed, has_waypoints);
+
+        match act(ctx, &mut npc, position) {
+            Some(goal) => navigation::set_goal(ctx, npc.entity_id, goal, NPC_SPEED),
+            None => navigation::stop(ctx, npc.entity_id),
+        }
+        ctx.db.npc().entity_id().update(npc);
+    }
+}
+
+// System: bring out hostile NPCs around the players of the room at night, clear them by day
+pub fn night_spawn_system(ctx: &ReducerContext, room_id: u32) {
+    let stalkers: Vec<u32> = ctx
+        .db
+        .entity()
+        .room_id()
+        .filter(room_id)
+        .filter(|e| e.kind == EntityKind::Npc)
+        .filter_map(|e| ctx.db.npc().entity_id().find(e.entity_id))
+        .filter(|npc| npc.night_spawn)
+        .map(|npc| npc.entity_id)
+        .collect();
+    if !world_clock::is_night(ctx) {
+        for entity_id in stalkers {
+            ecs::despawn(ctx, entity_id);
+        }
+        return;
+    }
+    if stalkers.len() >= NIGHT_NPCS_PER_ROOM || !ctx.rng().gen_bool(NIGHT_SPAWN_CHANCE) {
+        return;
+    }
+    let players: Vec<u32> = ctx.db.player().room_id().filter(room_id).map(|p| p.entity_id).collect();
+    if players.is_empty() {
+        return;
+    }
+    let Some(near) = ecs::position_of(ctx, players[ctx.rng().gen_range(0..players.len())]) else {
+        return;
+    };
+    let angle = ctx.rng().gen_range(0.0..std::f32::consts::TAU);
+    let distance = ctx.rng().gen_range(NIGHT_SPAWN_DISTANCE);
+    let (x, z) = (near.x + angle.cos() * distance, near.z + angle.sin() * distance);
+    let position = DbVector3::new(x, terrain::height_at(ctx, x, z), z);
+    spawn(ctx, room_id, NIGHT_NPC_NAME.to_string(), NpcBehavior::Chase, position, true);
+}
+
+pub fn spawn(ctx: &ReducerContext, room_id: u32, name: String, behavior: NpcBehavior, position: DbVector3, night_spawn: bool) -> u32 {
+    let entity_id = ecs::spawn(ctx, EntityKind::Npc, room_id);
+    ctx.db.transform().insert(ecs::Transform { entity_id, position, rotation: DbVector3::ZERO });
+    ctx.db.velocity().insert(ecs::Velocity { entity_id, linear: DbVector3::ZERO });
+    ctx.db.collider().insert(ecs::Collider { entity_id, radius: NPC_RADIUS });
+    ctx.db.health().insert(ecs::Health { entity_id, current: NPC_MAX_HEALTH, max: NPC_MAX_HEALTH });
+    ctx.db.npc().insert(Npc {
+        entity_id,
+        name,
+        behavior,
+        state: NpcState::Idle,
+        home: position,
+        sense_radius: DEFAULT_SENSE_RADIUS,
+        target: None,
+        destination: None,
+        waypoint_index: 0,
+        next_decision_at: ctx.timestamp,
+        night_spawn,
+    });
+    entity_id
+}
+
+// Reducer: Admin tool to place an NPC in a room
+#[spacetimedb::reducer]
+pub fn spawn_npc(ctx: &ReducerContext, room_id: u32, name: String, behavior: NpcBehavior, position: DbVector3) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if ctx.db.room().room_id().find(room_id).is_none() {
+        return Err(ReducerError::NotFound("No such room".to_string()));
+    }
+    spawn(ctx, room_id, name, behavior, position, false);
+    Ok(())
+}
+
+// Reducer: Admin tool, append a waypoint to an NPC's patrol route
+#[spacetimedb::reducer]
+pub fn add_patrol_waypoint(ctx: &ReducerContext, entity_id: u32, position: DbVector3) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if ctx.db.npc().entity_id().find(entity_id).is_none() {
+        return Err(ReducerError::NotFound("No such NPC".to_string()));
+    }
+    let order = ctx.db.patrol_waypoint().entity_id().filter(entity_id).count() as u32;
+    ctx.db.patrol_waypoint().insert(PatrolWaypoint {
+        waypoint_id: 0,
+        entity_id,
+        order,
+        position,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn despawn_npc(ctx: &ReducerContext, entity_id: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if ctx.db.npc().entity_id().find(entity_id).is_none() {
+        return Err(ReducerError::NotFound("No such NPC".to_string()));
+    }
+    ecs::despawn(ctx, entity_id);
+    Ok(())
+}
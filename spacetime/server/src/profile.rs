@@ -0,0 +1,73 @@
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+use crate::combat::{self, SavedDeath};
+use crate::ecs::transform;
+use crate::math::DbVector3;
+use crate::name;
+use crate::room;
+use crate::spawn;
+use crate::stats;
+use crate::Player;
+
+// Everything about a player that outlives a connection.
+// The `player` table only holds players that are online right now (the live session),
+// it is rebuilt from this row on connect and written back to it on disconnect.
+#[spacetimedb::table(name = player_profile, public)]
+pub struct PlayerProfile {
+    #[primary_key]
+    pub identity: Identity,
+    pub name: String,
+    pub last_position: DbVector3,
+    pub last_rotation: DbVector3,
+    // Set when the last session ended while dead, so reconnecting does not skip the respawn delay
+    pub death: Option<SavedDeath>,
+    pub sessions: u32,
+    pub play_time_secs: u64,
+    pub created_at: Timestamp,
+    pub last_seen: Timestamp,
+}
+
+// Fetch the sender's profile, creating it on their very first connect
+pub fn load_or_create(ctx: &ReducerContext) -> PlayerProfile {
+    match ctx.db.player_profile().identity().find(ctx.sender) {
+        Some(mut profile) => {
+            profile.sessions += 1;
+            profile.last_seen = ctx.timestamp;
+            ctx.db.player_profile().identity().update(profile)
+        }
+        None => {
+            log::info!("+ Profile INSERT, {}", ctx.sender);
+            let spawn = spawn::select(ctx, room::lobby(ctx), None);
+            ctx.db.player_profile().insert(PlayerProfile {
+                identity: ctx.sender,
+                name: name::default_name(ctx.sender),
+                last_position: spawn.position,
+                last_rotation: spawn.rotation,
+                death: None,
+                sessions: 1,
+                play_time_secs: 0,
+                created_at: ctx.timestamp,
+                last_seen: ctx.timestamp,
+            })
+        }
+    }
+}
+
+// Write a live session back into the profile so the player can resume from it
+pub fn save_session(ctx: &ReducerContext, player: &Player) {
+    let Some(mut profile) = ctx.db.player_profile().identity().find(player.identity) else {
+        log::warn!("No profile for {}, session not saved", player.identity);
+        return;
+    };
+    let session = ctx.timestamp.duration_since(player.joined_at).unwrap_or_default();
+    profile.name = player.name.clone();
+    if let Some(transform) = ctx.db.transform().entity_id().find(player.entity_id) {
+        profile.last_position = transform.position;
+        profile.last_rotation = transform.rotation;
+    }
+    profile.death = combat::saved_death(ctx, player.entity_id);
+    profile.play_time_secs += session.as_secs();
+    stats::add_play_time(ctx, player.identity, session.as_secs());
+    profile.last_seen = ctx.timestamp;
+    ctx.db.player_profile().identity().update(profile);
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use spacetimedb::{ReducerContext, Table, TimeDuration, Timestamp};
+
+use crate::chunk;
+use crate::combat::{self, DamageSource};
+use crate::ecs::{self, collider, entity, owner, transform, velocity, EntityKind};
+use crate::math::{self, DbVector3};
+
+pub const PROJECTILE_SPEED: f32 = 40.0;
+pub const PROJECTILE_RADIUS: f32 = 0.1;
+pub const PROJECTILE_DAMAGE: f32 = 25.0;
+pub const PROJECTILE_LIFETIME: Duration = Duration::from_secs(3);
+pub const FIRE_COOLDOWN: Duration = Duration::from_millis(250);
+// Units per second squared, pulls projectiles down along -y
+pub const GRAVITY: f32 = 9.81;
+// Spawn in front of the shooter at roughly chest height
+const MUZZLE_OFFSET: f32 = 0.7;
+const MUZZLE_HEIGHT: f32 = 0.5;
+
+// Component: ballistic projectile, moved by the regular movement system
+#[spacetimedb::table(name = projectile, public)]
+pub struct Projectile {
+    #[primary_key]
+    pub entity_id: u32,
+    // Entity that fired it, never hit by its own projectile
+    pub shooter: u32,
+    pub damage: f32,
+    pub expires_at: Timestamp,
+}
+
+pub fn fire(ctx: &ReducerContext, shooter: u32, direction: DbVector3) {
+    let (Some(origin), Some(room_id)) = (ecs::position_of(ctx, shooter), ecs::room_of(ctx, shooter)) else {
+        return;
+    };
+    let direction = direction.normalized();
+    let position = origin + direction * MUZZLE_OFFSET + DbVector3::new(0.0, MUZZLE_HEIGHT, 0.0);
+
+    let entity_id = ecs::spawn(ctx, EntityKind::Projectile, room_id);
+    ctx.db.transform().insert(ecs::Transform { entity_id, position, rotation: direction });
+    ctx.db.velocity().insert(ecs::Velocity { entity_id, linear: direction * PROJECTILE_SPEED });
+    ctx.db.collider().insert(ecs::Collider { entity_id, radius: PROJECTILE_RADIUS });
+    ctx.db.owner().insert(ecs::Owner { entity_id, identity: ctx.sender });
+    ctx.db.projectile().insert(Projectile {
+        entity_id,
+        shooter,
+        damage: PROJECTILE_DAMAGE,
+        expires_at: ctx.timestamp + TimeDuration::from_micros(PROJECTILE_LIFETIME.as_micros() as i64),
+    });
+}
+
+// Find the first live player or NPC of the room the projectile sweeps through this tick
+fn find_hit(ctx: &ReducerContext, room_id: u32, projectile: &Projectile, start: DbVector3, end: DbVector3) -> Option<u32> {
+    combat::targets(ctx, room_id, projectile.shooter)
+        .filter_map(|entity_id| {
+            let transform = ctx.db.transform().entity_id().find(entity_id)?;
+            let radius = ctx.db.collider().entity_id().find(entity_id)?.radius + PROJECTILE_RADIUS;
+            math::segment_hits_sphere(start, end, transform.position, radius)
+                .then(|| (entity_id, (transform.position - start).sqr_magnitude()))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity_id, _)| entity_id)
+}
+
+// System: gravity, expiry and hit detection. Runs before movement so it can
+// sweep the path the projectile is about to travel this tick.
+pub fn projectile_system(ctx: &ReducerContext, room_id: u32, dt: f32) {
+    let projectiles: Vec<Projectile> = ctx
+        .db
+        .entity()
+        .room_id()
+        .filter(room_id)
+        .filter(|e| e.kind == EntityKind::Projectile)
+        .filter_map(|e| ctx.db.projectile().entity_id().find(e.entity_id))
+        .collect();
+    for projectile in projectiles {
+        if projectile.expires_at <= ctx.timestamp {
+            ecs::despawn(ctx, projectile.entity_id);
+            continue;
+        }
+        let (Some(transform), Some(mut velocity)) = (
+            ctx.db.transform().entity_id().find(projectile.entity_id),
+            ctx.db.velocity().entity_id().find(projectile.entity_id),
+        ) else {
+            continue;
+        };
+
+        velocity.linear.y -= GRAVITY * dt;
+        let start = transform.position;
+        let end = start + velocity.linear * dt;
+
+        if let Some(target) = find_hit(ctx, room_id, &projectile, start, end) {
+            combat::apply_damage(ctx, target, projectile.damage, DamageSource::Entity(projectile.shooter));
+            ecs::despawn(ctx, projectile.entity_id);
+            continue;
+        }
+        if chunk::segment_hits_block(ctx, room_id, start, end) {
+            ecs::despawn(ctx, projectile.entity_id);
+            continue;
+        }
+        ctx.db.velocity().entity_id().update(velocity);
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use spacetimedb::{ReducerContext, Table, Timestamp};
+
+use crate::ecs::{self, transform};
+use crate::math::DbVector3;
+use crate::player;
+use crate::TICK_INTERVAL;
+
+// Samples kept per player, one per tick
+pub const HISTORY_SLOTS: usize = 20;
+// Nothing older than the history can be rewound to
+pub const MAX_REWIND: Duration = Duration::from_millis(TICK_INTERVAL.as_millis() as u64 * HISTORY_SLOTS as u64);
+
+// Ring buffer of recent player positions used for lag compensation.
+// Each entity owns at most HISTORY_SLOTS rows, the oldest is overwritten.
+#[spacetimedb::table(name = transform_history)]
+pub struct TransformHistory {
+    #[primary_key]
+    #[auto_inc]
+    history_id: u64,
+    #[index(btree)]
+    entity_id: u32,
+    position: DbVector3,
+    recorded_at: Timestamp,
+}
+
+// System: record where every player of the room ended up this tick
+pub fn history_system(ctx: &ReducerContext, room_id: u32) {
+    for player in ctx.db.player().room_id().filter(room_id) {
+        let Some(position) = ecs::position_of(ctx, player.entity_id) else {
+            continue;
+        };
+        let samples: Vec<TransformHistory> = ctx.db.transform_history().entity_id().filter(player.entity_id).collect();
+        if samples.len() < HISTORY_SLOTS {
+            ctx.db.transform_history().insert(TransformHistory {
+                history_id: 0,
+                entity_id: player.entity_id,
+                position,
+                recorded_at: ctx.timestamp,
+            });
+        } else if let Some(oldest) = samples.into_iter().min_by_key(|s| s.recorded_at) {
+            ctx.db.transform_history().history_id().update(TransformHistory {
+                position,
+                recorded_at: ctx.timestamp,
+                ..oldest
+            });
+        }
+    }
+}
+
+pub fn clear(ctx: &ReducerContext, entity_id: u32) {
+    ctx.db.transform_history().entity_id().delete(entity_id);
+}
+
+// Clamp the time a client claims to have seen the world at into the allowed window
+pub fn rewind_target(ctx: &ReducerContext, seen_at: Timestamp, max_rewind: Duration) -> Timestamp {
+    let now = ctx.timestamp.to_micros_since_unix_epoch();
+    let earliest = now - max_rewind.min(MAX_REWIND).as_micros() as i64;
+    Timestamp::from_micros_since_unix_epoch(seen_at.to_micros_since_unix_epoch().clamp(earliest, now))
+}
+
+// Where the entity was at `at`, interpolated between the two surrounding samples.
+// Falls back to the current position when there is no history.
+pub fn position_at(ctx: &ReducerContext, entity_id: u32, at: Timestamp) -> Option<DbVector3> {
+    let mut before: Option<TransformHistory> = None;
+    let mut after: Option<TransformHistory> = None;
+    for sample in ctx.db.transform_history().entity_id().filter(entity_id) {
+        if sample.recorded_at <= at {
+            if before.as_ref().is_none_or(|b| sample.recorded_at > b.recorded_at) {
+                before = Some(sample);
+            }
+        } else if after.as_ref().is_none_or(|a| sample.recorded_at < a.recorded_at) {
+            after = Some(sample);
+        }
+    }
+
+    match (before, after) {
+        (Some(before), Some(after)) => {
+            let start = before.recorded_at.to_micros_since_unix_epoch();
+            let span = (after.recorded_at.to_micros_since_unix_epoch() - start) as f32;
+            let t = (at.to_micros_since_unix_epoch() - start) as f32 / span;
+            Some(before.position + (after.position - before.position) * t)
+        }
+        (Some(sample), None) | (None, Some(sample)) => Some(sample.position),
+        (None, None) => ctx.db.transform().entity_id().find(entity_id).map(|t| t.position),
+    }
+}
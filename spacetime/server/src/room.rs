@@ -0,0 +1,162 @@
+use spacetimedb::{ReducerContext, Table, Timestamp};
+
+use crate::chunk::chunk;
+use crate::combat;
+use crate::ecs::{self, entity};
+use crate::error::{ReducerError, ReducerResult};
+use crate::match_state::{self, match_state as _};
+use crate::player;
+use crate::rewind;
+use crate::spawn;
+use crate::stats::match_score;
+use crate::team::{self, team as _};
+
+pub const LOBBY_NAME: &str = "Lobby";
+pub const DEFAULT_MAX_PLAYERS: u32 = 16;
+const MAX_ROOM_NAME_LEN: usize = 32;
+
+// A separate world inside the module. Players, entities and chat all carry
+// the room they belong to, and every room runs its own systems each tick.
+#[spacetimedb::table(name = room, public)]
+pub struct Room {
+    #[primary_key]
+    #[auto_inc]
+    pub room_id: u32,
+    #[unique]
+    pub name: String,
+    pub max_players: u32,
+    // The lobby is where everyone starts and it is never closed
+    pub persistent: bool,
+    pub created_at: Timestamp,
+}
+
+fn create(ctx: &ReducerContext, name: String, max_players: u32, persistent: bool) -> Result<Room, ReducerError> {
+    let room = ctx
+        .db
+        .room()
+        .try_insert(Room {
+            room_id: 0,
+            name,
+            max_players,
+            persistent,
+            created_at: ctx.timestamp,
+        })
+        .map_err(|_| ReducerError::InvalidInput("Room name is taken".to_string()))?;
+    log::info!("Room {} ({}) created", room.room_id, room.name);
+    team::seed(ctx, room.room_id);
+    match_state::start(ctx, room.room_id);
+    Ok(room)
+}
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.room().name().find(LOBBY_NAME.to_string()).is_none() {
+        let _ = create(ctx, LOBBY_NAME.to_string(), u32::MAX, true);
+    }
+}
+
+pub fn lobby(ctx: &ReducerContext) -> u32 {
+    ctx.db
+        .room()
+        .name()
+        .find(LOBBY_NAME.to_string())
+        .map(|r| r.room_id)
+        .unwrap_or_default()
+}
+
+pub fn player_count(ctx: &ReducerContext, room_id: u32) -> usize {
+    ctx.db.player().room_id().filter(room_id).count()
+}
+
+// Rooms that have players in them, idle rooms are not ticked
+pub fn active_rooms(ctx: &ReducerContext) -> Vec<u32> {
+    ctx.db
+        .room()
+        .iter()
+        .map(|r| r.room_id)
+        .filter(|&room_id| player_count(ctx, room_id) > 0)
+        .collect()
+}
+
+// Drop a room along with everything left in it once the last player is gone
+pub fn close_if_empty(ctx: &ReducerContext, room_id: u32) {
+    let Some(room) = ctx.db.room().room_id().find(room_id) else {
+        return;
+    };
+    if room.persistent || player_count(ctx, room_id) > 0 {
+        return;
+    }
+    let entities: Vec<u32> = ctx.db.entity().room_id().filter(room_id).map(|e| e.entity_id).collect();
+    for entity_id in entities {
+        ecs::despawn(ctx, entity_id);
+    }
+    ctx.db.team().room_id().delete(room_id);
+    ctx.db.chunk().room_id().delete(room_id);
+    ctx.db.match_score().room_id().delete(room_id);
+    // Pending match timers find no state and do nothing
+    ctx.db.match_state().room_id().delete(room_id);
+    ctx.db.room().room_id().delete(room_id);
+    log::info!("Room {} closed", room_id);
+}
+
+// Move the sender's player and its entity into `room_id`, on a fresh team and spawn point
+fn move_to(ctx: &ReducerContext, room_id: u32) -> ReducerResult {
+    let mut player = ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    if player.room_id == room_id {
+        return Err(ReducerError::InvalidInput("Already in that room".to_string()));
+    }
+    if combat::is_dead(ctx, player.entity_id) {
+        return Err(ReducerError::Dead);
+    }
+    let room = ctx
+        .db
+        .room()
+        .room_id()
+        .find(room_id)
+        .ok_or_else(|| ReducerError::NotFound("No such room".to_string()))?;
+    if player_count(ctx, room_id) >= room.max_players as usize {
+        return Err(ReducerError::InvalidInput("Room is full".to_string()));
+    }
+
+    let previous = player.room_id;
+    player.room_id = room_id;
+    let entity_id = player.entity_id;
+    ctx.db.player().identity().update(player);
+    ecs::set_room(ctx, entity_id, room_id);
+    // Old positions belong to the other room
+    rewind::clear(ctx, entity_id);
+    let team = team::assign(ctx, entity_id, room_id, None)?;
+    let spawn = spawn::select(ctx, room_id, team);
+    ecs::set_transform(ctx, entity_id, spawn.position, spawn.rotation);
+
+    close_if_empty(ctx, previous);
+    Ok(())
+}
+
+// Reducer: Open a new room and move into it
+#[spacetimedb::reducer]
+pub fn create_room(ctx: &ReducerContext, name: String, max_players: u32) -> ReducerResult {
+    let name = name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_ROOM_NAME_LEN {
+        return Err(ReducerError::InvalidInput(format!(
+            "Room names are 1 to {} characters",
+            MAX_ROOM_NAME_LEN
+        )));
+    }
+    if ctx.db.player().identity().find(ctx.sender).is_none() {
+        return Err(ReducerError::NotConnected);
+    }
+    let max_players = if max_players == 0 { DEFAULT_MAX_PLAYERS } else { max_players };
+    let room = create(ctx, name, max_players, false)?;
+    move_to(ctx, room.room_id)
+}
+
+#[spacetimedb::reducer]
+pub fn join_room(ctx: &ReducerContext, room_id: u32) -> ReducerResult {
+    move_to(ctx, room_id)
+}
+
+// Reducer: Go back to the lobby
+#[spacetimedb::reducer]
+pub fn leave_room(ctx: &ReducerContext) -> ReducerResult {
+    move_to(ctx, lobby(ctx))
+}
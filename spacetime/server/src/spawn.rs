@@ -0,0 +1,143 @@
+use spacetimedb::rand::Rng;
+use spacetimedb::{ReducerContext, SpacetimeType, Table};
+
+use crate::admin;
+use crate::config;
+use crate::ecs::transform;
+use crate::error::{ReducerError, ReducerResult};
+use crate::math::DbVector3;
+use crate::player;
+
+// Players within this distance of a spawn point count towards its crowd
+const CROWD_RADIUS: f32 = 10.0;
+
+#[derive(SpacetimeType, Debug, Clone, Copy, PartialEq)]
+pub enum SpawnStrategy {
+    Random,        // Any enabled spawn point
+    LeastCrowded,  // The one with the fewest players around it
+    TeamBased,     // Least crowded among the player's team spawns
+}
+
+#[spacetimedb::table(name = spawn_point, public)]
+pub struct SpawnPoint {
+    #[primary_key]
+    #[auto_inc]
+    spawn_id: u32,
+    position: DbVector3,
+    rotation: DbVector3,
+    // None is usable by every team
+    team: Option<u32>,
+    enabled: bool,
+}
+
+// Where to put a player, and which way they face
+pub struct SpawnLocation {
+    pub position: DbVector3,
+    pub rotation: DbVector3,
+}
+
+// A few spread out points so new worlds do not stack everyone at the origin
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.spawn_point().count() > 0 {
+        return;
+    }
+    let offsets = [(0.0, 0.0), (10.0, 0.0), (-10.0, 0.0), (0.0, 10.0), (0.0, -10.0)];
+    for (x, z) in offsets {
+        ctx.db.spawn_point().insert(SpawnPoint {
+            spawn_id: 0,
+            position: DbVector3::new(x, 0.0, z),
+            rotation: DbVector3::ZERO,
+            team: None,
+            enabled: true,
+        });
+    }
+}
+
+fn crowd_around(ctx: &ReducerContext, room_id: u32, position: DbVector3) -> usize {
+    ctx.db
+        .player()
+        .room_id()
+        .filter(room_id)
+        .filter_map(|p| ctx.db.transform().entity_id().find(p.entity_id))
+        .filter(|t| (t.position - position).sqr_magnitude() < CROWD_RADIUS * CROWD_RADIUS)
+        .count()
+}
+
+fn least_crowded(ctx: &ReducerContext, room_id: u32, points: &[SpawnPoint]) -> Option<usize> {
+    points
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, point)| crowd_around(ctx, room_id, point.position))
+        .map(|(i, _)| i)
+}
+
+// Pick a spawn location for a player on `team` in `room_id` using the configured strategy.
+// Every room shares the same spawn points. Falls back to the origin if none is enabled.
+pub fn select(ctx: &ReducerContext, room_id: u32, team: Option<u32>) -> SpawnLocation {
+    let mut points: Vec<SpawnPoint> = ctx.db.spawn_point().iter().filter(|p| p.enabled).collect();
+
+    let strategy = config::get(ctx).spawn_strategy;
+    if strategy == SpawnStrategy::TeamBased {
+        let team_points: Vec<SpawnPoint> = ctx
+            .db
+            .spawn_point()
+            .iter()
+            .filter(|p| p.enabled && (p.team.is_none() || p.team == team))
+            .collect();
+        if !team_points.is_empty() {
+            points = team_points;
+        }
+    }
+    if points.is_empty() {
+        return SpawnLocation {
+            position: DbVector3::ZERO,
+            rotation: DbVector3::ZERO,
+        };
+    }
+
+    let index = match strategy {
+        SpawnStrategy::Random => ctx.rng().gen_range(0..points.len()),
+        SpawnStrategy::LeastCrowded | SpawnStrategy::TeamBased => least_crowded(ctx, room_id, &points).unwrap_or(0),
+    };
+    let point = &points[index];
+    SpawnLocation {
+        position: point.position,
+        rotation: point.rotation,
+    }
+}
+
+#[spacetimedb::reducer]
+pub fn add_spawn_point(ctx: &ReducerContext, position: DbVector3, rotation: DbVector3, team: Option<u32>) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    ctx.db.spawn_point().insert(SpawnPoint {
+        spawn_id: 0,
+        position,
+        rotation,
+        team,
+        enabled: true,
+    });
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn set_spawn_point_enabled(ctx: &ReducerContext, spawn_id: u32, enabled: bool) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    let mut point = ctx
+        .db
+        .spawn_point()
+        .spawn_id()
+        .find(spawn_id)
+        .ok_or_else(|| ReducerError::NotFound("No such spawn point".to_string()))?;
+    point.enabled = enabled;
+    ctx.db.spawn_point().spawn_id().update(point);
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn remove_spawn_point(ctx: &ReducerContext, spawn_id: u32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if !ctx.db.spawn_point().spawn_id().delete(spawn_id) {
+        return Err(ReducerError::NotFound("No such spawn point".to_string()));
+    }
+    Ok(())
+}
@@ -0,0 +1,231 @@
+use std::time::Duration;
+
+use spacetimedb::{Identity, ReducerContext, ScheduleAt, Table};
+
+use crate::combat::DamageSource;
+use crate::error::{ReducerError, ReducerResult};
+use crate::match_state;
+use crate::player;
+use crate::profile::player_profile;
+use crate::team;
+use crate::Player;
+
+const KILL_POINTS: i64 = 10;
+const TEAM_KILL_PENALTY: i64 = 10;
+const WIN_POINTS: i64 = 50;
+pub const LEADERBOARD_SIZE: usize = 10;
+const LEADERBOARD_REFRESH: Duration = Duration::from_secs(10);
+
+// All-time numbers of an identity, only live matches count
+#[spacetimedb::table(name = player_stats, public)]
+pub struct PlayerStats {
+    #[primary_key]
+    pub identity: Identity,
+    pub kills: u32,
+    pub deaths: u32,
+    pub wins: u32,
+    pub score: i64,
+    pub play_time_secs: u64,
+}
+
+// Scoreboard of the match running in a room, cleared when the next one starts
+#[spacetimedb::table(name = match_score, public)]
+pub struct MatchScore {
+    #[primary_key]
+    #[auto_inc]
+    score_id: u64,
+    #[index(btree)]
+    pub room_id: u32,
+    pub match_id: u32,
+    pub identity: Identity,
+    pub name: String,
+    pub team_id: Option<u32>,
+    pub kills: u32,
+    pub deaths: u32,
+    pub score: i64,
+}
+
+// Best identities by score, rebuilt on a timer so clients can subscribe to a small table
+#[spacetimedb::table(name = leaderboard, public)]
+pub struct LeaderboardEntry {
+    #[primary_key]
+    pub rank: u32,
+    pub identity: Identity,
+    pub name: String,
+    pub kills: u32,
+    pub deaths: u32,
+    pub wins: u32,
+    pub score: i64,
+}
+
+#[spacetimedb::table(name = leaderboard_timer, scheduled(refresh_leaderboard))]
+pub struct LeaderboardTimer {
+    #[primary_key]
+    #[auto_inc]
+    scheduled_id: u64,
+    scheduled_at: ScheduleAt,
+}
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.leaderboard_timer().count() == 0 {
+        ctx.db.leaderboard_timer().insert(LeaderboardTimer {
+            scheduled_id: 0,
+            scheduled_at: ScheduleAt::Interval(LEADERBOARD_REFRESH.into()),
+        });
+    }
+}
+
+fn stats_of(ctx: &ReducerContext, identity: Identity) -> PlayerStats {
+    ctx.db.player_stats().identity().find(identity).unwrap_or(PlayerStats {
+        identity,
+        kills: 0,
+        deaths: 0,
+        wins: 0,
+        score: 0,
+        play_time_secs: 0,
+    })
+}
+
+fn save(ctx: &ReducerContext, stats: PlayerStats) {
+    if ctx.db.player_stats().identity().find(stats.identity).is_some() {
+        ctx.db.player_stats().identity().update(stats);
+    } else {
+        ctx.db.player_stats().insert(stats);
+    }
+}
+
+fn match_score_of(ctx: &ReducerContext, room_id: u32, identity: Identity) -> Option<MatchScore> {
+    ctx.db.match_score().room_id().filter(room_id).find(|s| s.identity == identity)
+}
+
+// Add to a player's all-time stats and to the scoreboard of the match in their room
+fn add(ctx: &ReducerContext, player: &Player, kills: u32, deaths: u32, points: i64) {
+    let mut stats = stats_of(ctx, player.identity);
+    stats.kills += kills;
+    stats.deaths += deaths;
+    stats.score += points;
+    save(ctx, stats);
+
+    let Some(state) = match_state::current(ctx, player.room_id) else {
+        return;
+    };
+    match match_score_of(ctx, player.room_id, player.identity) {
+        Some(mut score) => {
+            score.kills += kills;
+            score.deaths += deaths;
+            score.score += points;
+            ctx.db.match_score().score_id().update(score);
+        }
+        None => {
+            ctx.db.match_score().insert(MatchScore {
+                score_id: 0,
+                room_id: player.room_id,
+                match_id: state.match_id,
+                identity: player.identity,
+                name: player.name.clone(),
+                team_id: team::team_of(ctx, player.entity_id),
+                kills,
+                deaths,
+                score: points,
+            });
+        }
+    }
+}
+
+// A player entity died, credit the killer if it was another player
+pub fn record_kill(ctx: &ReducerContext, victim: u32, source: DamageSource) {
+    let Some(victim) = ctx.db.player().entity_id().find(victim) else {
+        return;
+    };
+    if !match_state::is_live(ctx, victim.room_id) {
+        return;
+    }
+    add(ctx, &victim, 0, 1, 0);
+
+    let DamageSource::Entity(killer) = source else {
+        return;
+    };
+    if killer == victim.entity_id {
+        return;
+    }
+    let Some(killer) = ctx.db.player().entity_id().find(killer) else {
+        return;
+    };
+    let killer_team = team::team_of(ctx, killer.entity_id);
+    if killer_team.is_some() && killer_team == team::team_of(ctx, victim.entity_id) {
+        add(ctx, &killer, 0, 0, -TEAM_KILL_PENALTY);
+    } else {
+        add(ctx, &killer, 1, 0, KILL_POINTS);
+    }
+}
+
+// Fresh scoreboard with everyone in the room at zero
+pub fn reset_scoreboard(ctx: &ReducerContext, room_id: u32, match_id: u32) {
+    ctx.db.match_score().room_id().delete(room_id);
+    for player in ctx.db.player().room_id().filter(room_id) {
+        ctx.db.match_score().insert(MatchScore {
+            score_id: 0,
+            room_id,
+            match_id,
+            identity: player.identity,
+            name: player.name.clone(),
+            team_id: team::team_of(ctx, player.entity_id),
+            kills: 0,
+            deaths: 0,
+            score: 0,
+        });
+    }
+}
+
+pub fn award_win(ctx: &ReducerContext, room_id: u32, team_id: u32) {
+    for player in ctx.db.player().room_id().filter(room_id) {
+        if team::team_of(ctx, player.entity_id) != Some(team_id) {
+            continue;
+        }
+        let mut stats = stats_of(ctx, player.identity);
+        stats.wins += 1;
+        save(ctx, stats);
+        add(ctx, &player, 0, 0, WIN_POINTS);
+    }
+}
+
+pub fn add_play_time(ctx: &ReducerContext, identity: Identity, secs: u64) {
+    let mut stats = stats_of(ctx, identity);
+    stats.play_time_secs += secs;
+    save(ctx, stats);
+}
+
+// Reducer: Scheduled, rebuild the leaderboard from everyone's stats
+#[spacetimedb::reducer]
+pub fn refresh_leaderboard(ctx: &ReducerContext, _timer: LeaderboardTimer) -> ReducerResult {
+    if ctx.sender != ctx.identity() {
+        return Err(ReducerError::PermissionDenied("Leaderboard is refreshed by the server".to_string()));
+    }
+    let mut best: Vec<PlayerStats> = ctx.db.player_stats().iter().collect();
+    best.sort_by_key(|s| std::cmp::Reverse(s.score));
+    best.truncate(LEADERBOARD_SIZE);
+
+    let old: Vec<LeaderboardEntry> = ctx.db.leaderboard().iter().collect();
+    for entry in old {
+        ctx.db.leaderboard().delete(entry);
+    }
+    for (i, stats) in best.into_iter().enumerate() {
+        let name = ctx
+            .db
+            .player_profile()
+            .identity()
+            .find(stats.identity)
+            .map(|p| p.name)
+            .unwrap_or_default();
+        ctx.db.leaderboard().insert(LeaderboardEntry {
+            rank: i as u32 + 1,
+            identity: stats.identity,
+            name,
+            kills: stats.kills,
+            deaths: stats.deaths,
+            wins: stats.wins,
+            score: stats.score,
+        });
+    }
+    Ok(())
+}
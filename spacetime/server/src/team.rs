@@ -0,0 +1,169 @@
+use spacetimedb::{ReducerContext, Table};
+
+use crate::combat::{self, DamageSource};
+use crate::config;
+use crate::ecs;
+use crate::error::{ReducerError, ReducerResult};
+use crate::match_state;
+use crate::player;
+use crate::spawn;
+
+// A chosen team may have at most this many more members than the smallest one
+const MAX_TEAM_IMBALANCE: usize = 1;
+const DEFAULT_TEAMS: &[&str] = &["Red", "Blue"];
+
+#[spacetimedb::table(name = team, public)]
+pub struct Team {
+    #[primary_key]
+    #[auto_inc]
+    pub team_id: u32,
+    // Every room has its own set of teams
+    #[index(btree)]
+    pub room_id: u32,
+    pub name: String,
+    // Kills of players on other teams
+    pub score: u32,
+}
+
+// Component: the team an entity fights for
+#[spacetimedb::table(name = team_member, public)]
+pub struct TeamMember {
+    #[primary_key]
+    pub entity_id: u32,
+    #[index(btree)]
+    pub team_id: u32,
+}
+
+pub fn seed(ctx: &ReducerContext, room_id: u32) {
+    if ctx.db.team().room_id().filter(room_id).next().is_some() {
+        return;
+    }
+    for name in DEFAULT_TEAMS {
+        ctx.db.team().insert(Team {
+            team_id: 0,
+            room_id,
+            name: name.to_string(),
+            score: 0,
+        });
+    }
+}
+
+pub fn team_of(ctx: &ReducerContext, entity_id: u32) -> Option<u32> {
+    ctx.db.team_member().entity_id().find(entity_id).map(|m| m.team_id)
+}
+
+// Members per team of the room, not counting `exclude`
+fn team_sizes(ctx: &ReducerContext, room_id: u32, exclude: u32) -> Vec<(u32, usize)> {
+    ctx.db
+        .team()
+        .room_id()
+        .filter(room_id)
+        .map(|team| {
+            let size = ctx
+                .db
+                .team_member()
+                .team_id()
+                .filter(team.team_id)
+                .filter(|m| m.entity_id != exclude)
+                .count();
+            (team.team_id, size)
+        })
+        .collect()
+}
+
+// Put the entity on `chosen`, or on the smallest team of the room when None.
+// Returns the team it ended up on, None if the room has no teams at all.
+pub fn assign(ctx: &ReducerContext, entity_id: u32, room_id: u32, chosen: Option<u32>) -> Result<Option<u32>, ReducerError> {
+    let sizes = team_sizes(ctx, room_id, entity_id);
+    let Some(&(smallest, smallest_size)) = sizes.iter().min_by_key(|(_, size)| *size) else {
+        remove(ctx, entity_id);
+        return Ok(None);
+    };
+    let team_id = match chosen {
+        None => smallest,
+        Some(team_id) => {
+            let &(_, size) = sizes
+                .iter()
+                .find(|(id, _)| *id == team_id)
+                .ok_or_else(|| ReducerError::NotFound("No such team".to_string()))?;
+            if size + 1 > smallest_size + MAX_TEAM_IMBALANCE {
+                return Err(ReducerError::InvalidInput("Team is full, pick another".to_string()));
+            }
+            team_id
+        }
+    };
+
+    let member = TeamMember { entity_id, team_id };
+    if ctx.db.team_member().entity_id().find(entity_id).is_some() {
+        ctx.db.team_member().entity_id().update(member);
+    } else {
+        ctx.db.team_member().insert(member);
+    }
+    Ok(Some(team_id))
+}
+
+pub fn remove(ctx: &ReducerContext, entity_id: u32) {
+    ctx.db.team_member().entity_id().delete(entity_id);
+}
+
+// Friendly fire rule of the damage pipeline: may `source` hurt `entity_id`?
+pub fn can_damage(ctx: &ReducerContext, entity_id: u32, source: DamageSource) -> bool {
+    let DamageSource::Entity(attacker) = source else {
+        return true;
+    };
+    if attacker == entity_id || config::get(ctx).friendly_fire {
+        return true;
+    }
+    match (team_of(ctx, attacker), team_of(ctx, entity_id)) {
+        (Some(a), Some(b)) => a != b,
+        _ => true,
+    }
+}
+
+// Award the killer's team a point for killing a player on another team
+pub fn record_kill(ctx: &ReducerContext, victim: u32, source: DamageSource) {
+    let DamageSource::Entity(killer) = source else {
+        return;
+    };
+    // NPCs are fair game but not worth a point
+    if ctx.db.player().entity_id().find(victim).is_none() {
+        return;
+    }
+    if !ecs::room_of(ctx, victim).is_some_and(|room_id| match_state::is_live(ctx, room_id)) {
+        return;
+    }
+    let Some(killer_team) = team_of(ctx, killer) else {
+        return;
+    };
+    if team_of(ctx, victim) == Some(killer_team) {
+        return;
+    }
+    if let Some(mut team) = ctx.db.team().team_id().find(killer_team) {
+        team.score += 1;
+        ctx.db.team().team_id().update(team);
+    }
+}
+
+pub fn reset_scores(ctx: &ReducerContext, room_id: u32) {
+    for mut team in ctx.db.team().room_id().filter(room_id) {
+        team.score = 0;
+        ctx.db.team().team_id().update(team);
+    }
+}
+
+// Reducer: Switch teams, None lets the server balance.
+// The player is moved to a spawn point of their new team.
+#[spacetimedb::reducer]
+pub fn join_team(ctx: &ReducerContext, team_id: Option<u32>) -> ReducerResult {
+    let player = ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    if combat::is_dead(ctx, player.entity_id) {
+        return Err(ReducerError::Dead);
+    }
+    let previous = team_of(ctx, player.entity_id);
+    let team = assign(ctx, player.entity_id, player.room_id, team_id)?;
+    if team != previous {
+        let spawn = spawn::select(ctx, player.room_id, team);
+        ecs::set_transform(ctx, player.entity_id, spawn.position, spawn.rotation);
+    }
+    Ok(())
+}
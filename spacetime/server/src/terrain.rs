@@ -0,0 +1,43 @@
+use spacetimedb::ReducerContext;
+
+use crate::chunk;
+use crate::config;
+use crate::ecs::{entity, transform, EntityKind};
+use crate::math::DbVector3;
+use crate::noise;
+
+// Deepest a walking entity drops in one tick, in blocks
+const MAX_DROP: i32 = 32;
+
+// Ground height at (x, z) of freshly generated terrain, see `noise::height`.
+// Blocks placed or broken since are not taken into account.
+pub fn height_at(ctx: &ReducerContext, x: f32, z: f32) -> f32 {
+    noise::height(config::get(ctx).terrain_seed, x, z)
+}
+
+// Top of the blocks under `position` in the room. A block where the feet are counts as
+// ground, so one block steps can be walked up. None if nothing solid is within MAX_DROP.
+pub fn ground_below(ctx: &ReducerContext, room_id: u32, position: DbVector3) -> Option<f32> {
+    let (x, feet, z) = (position.x.floor() as i32, position.y.floor() as i32, position.z.floor() as i32);
+    chunk::surface_below(ctx, room_id, x, feet, z, MAX_DROP).map(|ground| ground as f32)
+}
+
+// System: keep walking entities of the room on top of the blocks below them
+pub fn ground_system(ctx: &ReducerContext, room_id: u32) {
+    for entity in ctx.db.entity().room_id().filter(room_id) {
+        if !matches!(entity.kind, EntityKind::Player | EntityKind::Npc) {
+            continue;
+        }
+        let Some(mut transform) = ctx.db.transform().entity_id().find(entity.entity_id) else {
+            continue;
+        };
+        // Nothing solid below, e.g. at the bottom of a dug out shaft, leaves the entity where it is
+        let Some(ground) = ground_below(ctx, room_id, transform.position) else {
+            continue;
+        };
+        if transform.position.y != ground {
+            transform.position.y = ground;
+            ctx.db.transform().entity_id().update(transform);
+        }
+    }
+}
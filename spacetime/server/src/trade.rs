@@ -0,0 +1,198 @@
+use std::collections::BTreeMap;
+
+use spacetimedb::{Identity, ReducerContext, Table, Timestamp};
+
+use crate::combat;
+use crate::currency::{self, LedgerReason};
+use crate::error::{ReducerError, ReducerResult};
+use crate::inventory::{self, ItemStack};
+use crate::player;
+
+// Different items one side can put up in a single trade
+const MAX_OFFER_STACKS: usize = 8;
+
+// An open trade between two players. Items only change hands once both have accepted,
+// and an accepted offer cannot change underneath the other side.
+#[spacetimedb::table(name = trade, public)]
+pub struct Trade {
+    #[primary_key]
+    #[auto_inc]
+    pub trade_id: u32,
+    #[index(btree)]
+    pub proposer: Identity,
+    #[index(btree)]
+    pub partner: Identity,
+    pub proposer_offer: Vec<ItemStack>,
+    pub partner_offer: Vec<ItemStack>,
+    pub proposer_coins: u64,
+    pub partner_coins: u64,
+    pub proposer_accepted: bool,
+    pub partner_accepted: bool,
+    pub created_at: Timestamp,
+}
+
+fn open_trade_of(ctx: &ReducerContext, identity: Identity) -> Option<Trade> {
+    ctx.db
+        .trade()
+        .proposer()
+        .filter(identity)
+        .chain(ctx.db.trade().partner().filter(identity))
+        .next()
+}
+
+// The sender's trade, if they are one of its two sides
+fn own_trade(ctx: &ReducerContext, trade_id: u32) -> Result<Trade, ReducerError> {
+    ctx.db
+        .trade()
+        .trade_id()
+        .find(trade_id)
+        .filter(|t| t.proposer == ctx.sender || t.partner == ctx.sender)
+        .ok_or_else(|| ReducerError::NotFound("No such trade".to_string()))
+}
+
+// Merge repeated items and check the owner has all of them
+fn validate_offer(ctx: &ReducerContext, owner: Identity, offer: Vec<ItemStack>) -> Result<Vec<ItemStack>, ReducerError> {
+    let mut merged: BTreeMap<u32, u32> = BTreeMap::new();
+    for stack in offer {
+        if stack.quantity == 0 {
+            return Err(ReducerError::InvalidInput("Stacks cannot be empty".to_string()));
+        }
+        let total = merged.entry(stack.item_id).or_default();
+        *total = total.saturating_add(stack.quantity);
+    }
+    if merged.len() > MAX_OFFER_STACKS {
+        return Err(ReducerError::InvalidInput("Too many items in one offer".to_string()));
+    }
+    for (&item_id, &quantity) in &merged {
+        inventory::definition(ctx, item_id)?;
+        if inventory::count(ctx, owner, item_id) < quantity {
+            return Err(ReducerError::InvalidInput("Not enough items".to_string()));
+        }
+    }
+    Ok(merged.into_iter().map(|(item_id, quantity)| ItemStack { item_id, quantity }).collect())
+}
+
+// Swap both offers in one go. Any failure fails the reducer, which rolls everything back.
+fn execute(ctx: &ReducerContext, trade: &Trade) -> ReducerResult {
+    for stack in &trade.proposer_offer {
+        inventory::take(ctx, trade.proposer, stack.item_id, stack.quantity)?;
+    }
+    for stack in &trade.partner_offer {
+        inventory::take(ctx, trade.partner, stack.item_id, stack.quantity)?;
+    }
+    let deliveries = trade
+        .proposer_offer
+        .iter()
+        .map(|s| (trade.partner, s))
+        .chain(trade.partner_offer.iter().map(|s| (trade.proposer, s)));
+    for (receiver, stack) in deliveries {
+        if inventory::give(ctx, receiver, stack.item_id, stack.quantity) > 0 {
+            return Err(ReducerError::InvalidInput("Inventory is full".to_string()));
+        }
+    }
+    let payments = [
+        (trade.proposer, trade.partner, trade.proposer_coins),
+        (trade.partner, trade.proposer, trade.partner_coins),
+    ];
+    for (payer, payee, coins) in payments {
+        if coins > 0 {
+            currency::debit(ctx, payer, coins, LedgerReason::Trade, Some(payee))?;
+            currency::credit(ctx, payee, coins, LedgerReason::Trade, Some(payer))?;
+        }
+    }
+    Ok(())
+}
+
+// Drop any trade the player is part of, e.g. when they leave
+pub fn cancel_all(ctx: &ReducerContext, identity: Identity) {
+    while let Some(trade) = open_trade_of(ctx, identity) {
+        ctx.db.trade().trade_id().delete(trade.trade_id);
+    }
+}
+
+// Reducer: Open a trade with another player of the same room
+#[spacetimedb::reducer]
+pub fn propose_trade(ctx: &ReducerContext, partner: Identity) -> ReducerResult {
+    let player = ctx.db.player().identity().find(ctx.sender).ok_or(ReducerError::NotConnected)?;
+    if combat::is_dead(ctx, player.entity_id) {
+        return Err(ReducerError::Dead);
+    }
+    if partner == ctx.sender {
+        return Err(ReducerError::InvalidInput("Cannot trade with yourself".to_string()));
+    }
+    let other = ctx
+        .db
+        .player()
+        .identity()
+        .find(partner)
+        .filter(|p| p.room_id == player.room_id)
+        .ok_or_else(|| ReducerError::NotFound("Player is not in this room".to_string()))?;
+    if open_trade_of(ctx, player.identity).is_some() || open_trade_of(ctx, other.identity).is_some() {
+        return Err(ReducerError::InvalidInput("Already trading".to_string()));
+    }
+    ctx.db.trade().insert(Trade {
+        trade_id: 0,
+        proposer: ctx.sender,
+        partner,
+        proposer_offer: Vec::new(),
+        partner_offer: Vec::new(),
+        proposer_coins: 0,
+        partner_coins: 0,
+        proposer_accepted: false,
+        partner_accepted: false,
+        created_at: ctx.timestamp,
+    });
+    Ok(())
+}
+
+// Reducer: Replace the sender's side of the trade. The other side has to accept again.
+#[spacetimedb::reducer]
+pub fn update_offer(ctx: &ReducerContext, trade_id: u32, offer: Vec<ItemStack>, coins: u64) -> ReducerResult {
+    let mut trade = own_trade(ctx, trade_id)?;
+    let offer = validate_offer(ctx, ctx.sender, offer)?;
+    if currency::balance_of(ctx, ctx.sender) < coins {
+        return Err(ReducerError::InvalidInput("Not enough coins".to_string()));
+    }
+    if trade.proposer == ctx.sender {
+        if trade.proposer_accepted {
+            return Err(ReducerError::InvalidInput("Offer is locked after accepting".to_string()));
+        }
+        trade.proposer_offer = offer;
+        trade.proposer_coins = coins;
+        trade.partner_accepted = false;
+    } else {
+        if trade.partner_accepted {
+            return Err(ReducerError::InvalidInput("Offer is locked after accepting".to_string()));
+        }
+        trade.partner_offer = offer;
+        trade.partner_coins = coins;
+        trade.proposer_accepted = false;
+    }
+    ctx.db.trade().trade_id().update(trade);
+    Ok(())
+}
+
+// Reducer: Accept the trade as it stands, the swap happens once both sides have
+#[spacetimedb::reducer]
+pub fn accept_trade(ctx: &ReducerContext, trade_id: u32) -> ReducerResult {
+    let mut trade = own_trade(ctx, trade_id)?;
+    if trade.proposer == ctx.sender {
+        trade.proposer_accepted = true;
+    } else {
+        trade.partner_accepted = true;
+    }
+    if trade.proposer_accepted && trade.partner_accepted {
+        execute(ctx, &trade)?;
+        ctx.db.trade().trade_id().delete(trade_id);
+    } else {
+        ctx.db.trade().trade_id().update(trade);
+    }
+    Ok(())
+}
+
+#[spacetimedb::reducer]
+pub fn cancel_trade(ctx: &ReducerContext, trade_id: u32) -> ReducerResult {
+    own_trade(ctx, trade_id)?;
+    ctx.db.trade().trade_id().delete(trade_id);
+    Ok(())
+}
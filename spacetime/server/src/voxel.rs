@@ -0,0 +1,105 @@
+// Block storage shared with the client library, which builds this file as well.
+// A chunk is a CHUNK_SIZE cube of one byte blocks, run length encoded on the wire.
+
+use crate::noise;
+
+pub const CHUNK_SIZE: i32 = 16;
+pub const CHUNK_VOLUME: usize = (CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE) as usize;
+
+pub const BLOCK_AIR: u8 = 0;
+pub const BLOCK_GRASS: u8 = 1;
+pub const BLOCK_DIRT: u8 = 2;
+pub const BLOCK_STONE: u8 = 3;
+pub const BLOCK_WOOD: u8 = 4;
+// Block ids are below this
+pub const BLOCK_KINDS: u8 = 5;
+
+// Blocks of dirt between the grass and the stone
+const DIRT_DEPTH: i32 = 3;
+
+// Chunk coordinates holding the block at (x, y, z)
+pub fn chunk_of(x: i32, y: i32, z: i32) -> (i32, i32, i32) {
+    (x.div_euclid(CHUNK_SIZE), y.div_euclid(CHUNK_SIZE), z.div_euclid(CHUNK_SIZE))
+}
+
+// Index of the block at world (x, y, z) inside its chunk's block array
+pub fn local_index(x: i32, y: i32, z: i32) -> usize {
+    let (lx, ly, lz) = (x.rem_euclid(CHUNK_SIZE), y.rem_euclid(CHUNK_SIZE), z.rem_euclid(CHUNK_SIZE));
+    ((ly * CHUNK_SIZE + lz) * CHUNK_SIZE + lx) as usize
+}
+
+// The room in the upper 64 bits, below it the three chunk coordinates with 21 bits each
+pub fn chunk_key(room_id: u32, cx: i32, cy: i32, cz: i32) -> u128 {
+    const MASK: u64 = (1 << 21) - 1;
+    let coords = ((cx as u64 & MASK) << 42) | ((cy as u64 & MASK) << 21) | (cz as u64 & MASK);
+    ((room_id as u128) << 64) | coords as u128
+}
+
+// Runs of (length, block) pairs, a run is at most 255 blocks long
+pub fn compress(blocks: &[u8]) -> Vec<u8> {
+    let mut data = Vec::new();
+    let mut iter = blocks.iter().copied().peekable();
+    while let Some(block) = iter.next() {
+        let mut run: u8 = 1;
+        while run < u8::MAX && iter.peek() == Some(&block) {
+            iter.next();
+            run += 1;
+        }
+        data.push(run);
+        data.push(block);
+    }
+    data
+}
+
+pub fn decompress(data: &[u8]) -> Vec<u8> {
+    let mut blocks = Vec::with_capacity(CHUNK_VOLUME);
+    for pair in data.chunks_exact(2) {
+        blocks.extend(std::iter::repeat_n(pair[1], pair[0] as usize));
+    }
+    blocks.resize(CHUNK_VOLUME, BLOCK_AIR);
+    blocks
+}
+
+// Fresh blocks of a chunk, filled up to the terrain height of `seed`
+pub fn generate(seed: u64, cx: i32, cy: i32, cz: i32) -> Vec<u8> {
+    let mut blocks = vec![BLOCK_AIR; CHUNK_VOLUME];
+    let (x0, y0, z0) = (cx * CHUNK_SIZE, cy * CHUNK_SIZE, cz * CHUNK_SIZE);
+    for z in z0..z0 + CHUNK_SIZE {
+        for x in x0..x0 + CHUNK_SIZE {
+            let ground = noise::height(seed, x as f32 + 0.5, z as f32 + 0.5).floor() as i32;
+            for y in y0..(y0 + CHUNK_SIZE).min(ground + 1) {
+                blocks[local_index(x, y, z)] = if y == ground {
+                    BLOCK_GRASS
+                } else if y >= ground - DIRT_DEPTH {
+                    BLOCK_DIRT
+                } else {
+                    BLOCK_STONE
+                };
+            }
+        }
+    }
+    blocks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compress_round_trips() {
+        let mut blocks = generate(7, 0, 0, 0);
+        // Runs longer than 255 and single blocks in between
+        blocks[local_index(3, 15, 3)] = BLOCK_WOOD;
+        blocks[local_index(4, 15, 3)] = BLOCK_STONE;
+        assert_eq!(decompress(&compress(&blocks)), blocks);
+
+        let air = vec![BLOCK_AIR; CHUNK_VOLUME];
+        assert_eq!(decompress(&compress(&air)), air);
+    }
+
+    #[test]
+    fn chunk_keys_differ_per_room() {
+        assert_ne!(chunk_key(1, 0, 0, 0), chunk_key(2, 0, 0, 0));
+        assert_ne!(chunk_key(1, -1, 0, 0), chunk_key(1, 1, 0, 0));
+    }
+}
@@ -0,0 +1,79 @@
+use spacetimedb::{ReducerContext, Table, Timestamp};
+
+use crate::admin;
+use crate::config;
+use crate::error::{ReducerError, ReducerResult};
+
+const CLOCK_ID: u32 = 0;
+// Fractions of a day, 0 is midnight and 0.5 is noon
+const SUNRISE: f32 = 0.25;
+const SUNSET: f32 = 0.75;
+// New worlds start in the morning
+const START_TIME: f32 = 0.3;
+
+// Time of day of the whole world, a single row moved along by the tick.
+// Clients drive their lighting from it.
+#[spacetimedb::table(name = world_clock, public)]
+pub struct WorldClock {
+    #[primary_key]
+    id: u32,
+    // Days since the world was created
+    pub day: u32,
+    // Fraction of the current day in [0, 1), 0 is midnight
+    pub time_of_day: f32,
+    pub updated_at: Timestamp,
+}
+
+pub fn seed(ctx: &ReducerContext) {
+    if ctx.db.world_clock().id().find(CLOCK_ID).is_none() {
+        ctx.db.world_clock().insert(WorldClock {
+            id: CLOCK_ID,
+            day: 0,
+            time_of_day: START_TIME,
+            updated_at: ctx.timestamp,
+        });
+    }
+}
+
+pub fn time_of_day(ctx: &ReducerContext) -> f32 {
+    ctx.db.world_clock().id().find(CLOCK_ID).map_or(START_TIME, |c| c.time_of_day)
+}
+
+pub fn is_night(ctx: &ReducerContext) -> bool {
+    let time = time_of_day(ctx);
+    !(SUNRISE..SUNSET).contains(&time)
+}
+
+// Move the clock forward by `dt` seconds of a day lasting `day_length_secs`
+pub fn advance(ctx: &ReducerContext, dt: f32) {
+    let Some(mut clock) = ctx.db.world_clock().id().find(CLOCK_ID) else {
+        return;
+    };
+    let day_length = config::get(ctx).day_length_secs.max(1) as f32;
+    clock.time_of_day += dt / day_length;
+    if clock.time_of_day >= 1.0 {
+        clock.time_of_day = clock.time_of_day.fract();
+        clock.day += 1;
+    }
+    clock.updated_at = ctx.timestamp;
+    ctx.db.world_clock().id().update(clock);
+}
+
+// Reducer: Admin tool to jump to a time of day, 0 is midnight and 0.5 is noon
+#[spacetimedb::reducer]
+pub fn set_time_of_day(ctx: &ReducerContext, time_of_day: f32) -> ReducerResult {
+    admin::require_admin(ctx)?;
+    if !(0.0..1.0).contains(&time_of_day) {
+        return Err(ReducerError::InvalidInput("Time of day must be in [0, 1)".to_string()));
+    }
+    let mut clock = ctx
+        .db
+        .world_clock()
+        .id()
+        .find(CLOCK_ID)
+        .ok_or_else(|| ReducerError::Internal("World clock is missing".to_string()))?;
+    clock.time_of_day = time_of_day;
+    clock.updated_at = ctx.timestamp;
+    ctx.db.world_clock().id().update(clock);
+    Ok(())
+}
@@ -7,17 +7,448 @@ const Render = @import("render.zig");
 
 pub const db = struct {
     pub const Connection = opaque {
-        pub fn connect() !*@This() {
-            return c.connect_to_db_ffi() orelse error.Connect;
+        /// `resume_session` reuses the saved token so the player picks up where they left off.
+        pub fn connect(resume_session: bool) !*@This() {
+            return c.connect_to_db_ffi(resume_session) orelse error.Connect;
         }
         pub fn disconnect(self: *@This()) void {
             c.free_db_connection(self);
         }
+        pub fn frameTick(self: *@This()) bool {
+            return c.db_frame_tick_ffi(self);
+        }
+        pub fn move(self: *@This(), x: f32, y: f32, z: f32) bool {
+            return c.player_move_ffi(self, x, y, z);
+        }
+        pub fn setName(self: *@This(), name: [*:0]const u8) bool {
+            return c.set_player_name_ffi(self, name);
+        }
+        pub fn sendChat(self: *@This(), channel: ChatMessage.Channel, text: [*:0]const u8) bool {
+            return c.send_chat_message_ffi(self, channel, text);
+        }
+        pub fn fire(self: *@This(), x: f32, y: f32, z: f32) bool {
+            return c.player_fire_ffi(self, x, y, z);
+        }
+        pub fn attack(self: *@This(), kind: AttackKind, x: f32, y: f32, z: f32) bool {
+            return c.player_attack_ffi(self, kind, x, y, z);
+        }
+        pub fn gather(self: *@This(), node_id: u32) bool {
+            return c.player_gather_ffi(self, node_id);
+        }
+        /// Fills `out` with trees and ore veins, returns the filled part.
+        pub fn resourceNodes(self: *@This(), out: []ResourceNode) []ResourceNode {
+            return out[0..c.resource_nodes_ffi(self, out.ptr, out.len)];
+        }
+        /// 0 lets the server pick the smallest team.
+        pub fn joinTeam(self: *@This(), team_id: u32) bool {
+            return c.join_team_ffi(self, team_id);
+        }
+        pub fn localTeam(self: *@This()) ?Team {
+            var team: Team = undefined;
+            return if (c.local_team_ffi(self, &team)) team else null;
+        }
+        /// Fills `out` with the other players on our team, returns the filled part.
+        pub fn teammates(self: *@This(), out: []Teammate) []Teammate {
+            return out[0..c.teammates_ffi(self, out.ptr, out.len)];
+        }
+        pub fn matchState(self: *@This()) ?MatchState {
+            var state: MatchState = undefined;
+            return if (c.match_state_ffi(self, &state)) state else null;
+        }
+        /// `max_players` 0 uses the server default.
+        pub fn createRoom(self: *@This(), name: [*:0]const u8, max_players: u32) bool {
+            return c.create_room_ffi(self, name, max_players);
+        }
+        pub fn joinRoom(self: *@This(), room_id: u32) bool {
+            return c.join_room_ffi(self, room_id);
+        }
+        pub fn leaveRoom(self: *@This()) bool {
+            return c.leave_room_ffi(self);
+        }
+        /// 0 until our player has arrived.
+        pub fn currentRoom(self: *@This()) u32 {
+            return c.current_room_ffi(self);
+        }
+        /// Fills `out` with open rooms, returns the filled part.
+        pub fn rooms(self: *@This(), out: []Room) []Room {
+            return out[0..c.rooms_ffi(self, out.ptr, out.len)];
+        }
+        /// Fills `out` with the all-time best players, returns the filled part.
+        pub fn leaderboard(self: *@This(), out: []ScoreEntry) []ScoreEntry {
+            return out[0..c.leaderboard_ffi(self, out.ptr, out.len)];
+        }
+        /// Fills `out` with the scoreboard of the match in our room, returns the filled part.
+        pub fn scoreboard(self: *@This(), out: []ScoreEntry) []ScoreEntry {
+            return out[0..c.scoreboard_ffi(self, out.ptr, out.len)];
+        }
+        pub fn localStats(self: *@This()) ?ScoreEntry {
+            var entry: ScoreEntry = undefined;
+            return if (c.local_stats_ffi(self, &entry)) entry else null;
+        }
+        /// Fills `out` with the NPCs of our room, returns the filled part.
+        pub fn npcs(self: *@This(), out: []Npc) []Npc {
+            return out[0..c.npcs_ffi(self, out.ptr, out.len)];
+        }
+        /// Fills `out` with the item drops of our room, returns the filled part.
+        pub fn itemDrops(self: *@This(), out: []ItemDrop) []ItemDrop {
+            return out[0..c.item_drops_ffi(self, out.ptr, out.len)];
+        }
+        pub fn worldClock(self: *@This()) ?WorldClock {
+            var clock: WorldClock = undefined;
+            return if (c.world_clock_ffi(self, &clock)) clock else null;
+        }
+        /// Ground height at (x, z), 0 until the world config has arrived.
+        pub fn terrainHeight(self: *@This(), x: f32, z: f32) f32 {
+            return c.terrain_height_ffi(self, x, z);
+        }
+        /// Copies a loaded chunk into `out`, false until it has arrived.
+        pub fn chunkBlocks(self: *@This(), cx: i32, cy: i32, cz: i32, out: *Chunk.Blocks) bool {
+            return c.chunk_blocks_ffi(self, cx, cy, cz, out, out.len);
+        }
+        /// null while the block's chunk is not loaded.
+        pub fn blockAt(self: *@This(), x: i32, y: i32, z: i32) ?Block {
+            const block = c.block_at_ffi(self, x, y, z);
+            return if (block == 255) null else @enumFromInt(block);
+        }
+        pub fn placeBlock(self: *@This(), x: i32, y: i32, z: i32, block: Block) bool {
+            return c.place_block_ffi(self, x, y, z, block);
+        }
+        pub fn breakBlock(self: *@This(), x: i32, y: i32, z: i32) bool {
+            return c.break_block_ffi(self, x, y, z);
+        }
+        /// Fills `out` with our non-empty inventory slots in slot order, returns the filled part.
+        pub fn inventory(self: *@This(), out: []InventorySlot) []InventorySlot {
+            return out[0..c.inventory_ffi(self, out.ptr, out.len)];
+        }
+        /// A slot of our inventory that changed, with what it holds now.
+        pub fn pollInventoryEvent(self: *@This()) ?InventorySlot {
+            var slot: InventorySlot = undefined;
+            return if (c.poll_inventory_event_ffi(self, &slot)) slot else null;
+        }
+        pub fn itemDefinition(self: *@This(), item_id: u32) ?ItemDefinition {
+            var item: ItemDefinition = undefined;
+            return if (c.item_definition_ffi(self, item_id, &item)) item else null;
+        }
+        pub fn moveItem(self: *@This(), from: u32, to: u32) bool {
+            return c.move_item_ffi(self, from, to);
+        }
+        /// Moves `quantity` items from `from` into the empty slot `to`.
+        pub fn splitItem(self: *@This(), from: u32, to: u32, quantity: u32) bool {
+            return c.split_item_ffi(self, from, to, quantity);
+        }
+        pub fn dropItem(self: *@This(), slot: u32, quantity: u32) bool {
+            return c.drop_item_ffi(self, slot, quantity);
+        }
+        pub fn useItem(self: *@This(), slot: u32) bool {
+            return c.use_item_ffi(self, slot);
+        }
+        /// Fills `out` with every recipe, returns the filled part.
+        pub fn recipes(self: *@This(), out: []Recipe) []Recipe {
+            return out[0..c.recipes_ffi(self, out.ptr, out.len)];
+        }
+        /// Fills `out` with our pending crafts, soonest first, returns the filled part.
+        pub fn craftJobs(self: *@This(), out: []CraftJob) []CraftJob {
+            return out[0..c.craft_jobs_ffi(self, out.ptr, out.len)];
+        }
+        pub fn craft(self: *@This(), recipe_id: u32) bool {
+            return c.craft_ffi(self, recipe_id);
+        }
+        pub fn currentTrade(self: *@This()) ?Trade {
+            var trade: Trade = undefined;
+            return if (c.current_trade_ffi(self, &trade)) trade else null;
+        }
+        /// `entity_id` is the other player's entity, they must be in our room.
+        pub fn proposeTrade(self: *@This(), entity_id: u32) bool {
+            return c.propose_trade_ffi(self, entity_id);
+        }
+        pub fn updateOffer(self: *@This(), trade_id: u32, offer: []const ItemStack, coins: u64) bool {
+            return c.update_offer_ffi(self, trade_id, offer.ptr, offer.len, coins);
+        }
+        pub fn acceptTrade(self: *@This(), trade_id: u32) bool {
+            return c.accept_trade_ffi(self, trade_id);
+        }
+        pub fn cancelTrade(self: *@This(), trade_id: u32) bool {
+            return c.cancel_trade_ffi(self, trade_id);
+        }
+        pub fn balance(self: *@This()) u64 {
+            return c.balance_ffi(self);
+        }
+        /// Fills `out` with our latest ledger entries, newest first, returns the filled part.
+        pub fn ledgerEntries(self: *@This(), out: []LedgerEntry) []LedgerEntry {
+            return out[0..c.ledger_entries_ffi(self, out.ptr, out.len)];
+        }
+        /// `entity_id` is the other player's entity, they must be in our room.
+        pub fn transfer(self: *@This(), entity_id: u32, amount: u64) bool {
+            return c.transfer_ffi(self, entity_id, amount);
+        }
+        /// Fills `out` with projectiles in flight, returns the filled part.
+        pub fn projectiles(self: *@This(), out: []Projectile) []Projectile {
+            return out[0..c.projectile_positions_ffi(self, out.ptr, out.len)];
+        }
+    };
+
+    pub const ChatMessage = extern struct {
+        pub const Channel = enum(u8) { global = 0, proximity = 1 };
+
+        channel: Channel,
+        sender_name: [32]u8,
+        text: [1024]u8,
+
+        pub fn poll() ?ChatMessage {
+            var message: ChatMessage = undefined;
+            return if (c.poll_chat_message_ffi(&message)) message else null;
+        }
+    };
+
+    /// Pick a name before connecting, it is sent as soon as the connection is up.
+    pub fn setPendingName(name: [*:0]const u8) void {
+        _ = c.set_player_name_ffi(null, name);
+    }
+
+    pub const ReducerError = extern struct {
+        pub const Code = enum(c_int) {
+            unknown = 0,
+            not_connected = 1,
+            invalid_input = 2,
+            rate_limited = 3,
+            internal = 4,
+            permission_denied = 5,
+            not_found = 6,
+            dead = 7,
+        };
+
+        code: Code,
+        reducer: [32]u8,
+        message: [128]u8,
+
+        pub fn poll() ?ReducerError {
+            var err: ReducerError = undefined;
+            return if (c.poll_reducer_error_ffi(&err)) err else null;
+        }
+    };
+
+    pub const ScoreEntry = extern struct {
+        kills: u32,
+        deaths: u32,
+        score: i64,
+        name: [32]u8,
+    };
+
+    pub const Room = extern struct {
+        room_id: u32,
+        max_players: u32,
+        name: [32]u8,
+    };
+
+    pub const Team = extern struct {
+        team_id: u32,
+        score: u32,
+        name: [32]u8,
+    };
+
+    pub const Teammate = extern struct {
+        entity_id: u32,
+        name: [32]u8,
+    };
+
+    pub const MatchState = extern struct {
+        pub const Phase = enum(u8) { warmup = 0, in_progress = 1, ended = 2 };
+
+        match_id: u32,
+        phase: Phase,
+        seconds_left: f32,
+        /// 0 for none or a tie
+        winner_team: u32,
+    };
+
+    pub const WorldClock = extern struct {
+        day: u32,
+        /// 0 is midnight, 0.5 is noon
+        time_of_day: f32,
+        day_length_secs: u32,
+
+        pub fn isNight(self: WorldClock) bool {
+            return self.time_of_day < 0.25 or self.time_of_day >= 0.75;
+        }
+    };
+
+    pub const AttackKind = enum(u8) { melee = 0, hitscan = 1 };
+
+    pub const Npc = extern struct {
+        pub const State = enum(u8) { idle = 0, wandering = 1, patrolling = 2, chasing = 3, fleeing = 4 };
+
+        entity_id: u32,
+        x: f32,
+        y: f32,
+        z: f32,
+        state: State,
+    };
+
+    pub const Block = enum(u8) { air = 0, grass = 1, dirt = 2, stone = 3, wood = 4, _ };
+
+    pub const Chunk = extern struct {
+        pub const size = 16;
+        /// x first, then z, then y
+        pub const Blocks = [size * size * size]u8;
+
+        x: i32,
+        y: i32,
+        z: i32,
+
+        /// A chunk that was loaded or changed and needs a new mesh.
+        pub fn pollUpdate() ?Chunk {
+            var chunk: Chunk = undefined;
+            return if (c.poll_chunk_update_ffi(&chunk)) chunk else null;
+        }
+    };
+
+    pub const InventorySlot = extern struct {
+        slot: u32,
+        /// 0 for an empty slot
+        item_id: u32,
+        quantity: u32,
+    };
+
+    pub const ItemDefinition = extern struct {
+        pub const Kind = enum(u8) { material = 0, block = 1, food = 2 };
+
+        item_id: u32,
+        max_stack: u32,
+        kind: Kind,
+        /// Block id for blocks, health restored for food
+        value: f32,
+        name: [32]u8,
+    };
+
+    pub const ResourceNode = extern struct {
+        pub const Kind = enum(u8) { tree = 0, ore = 1 };
+
+        node_id: u32,
+        kind: Kind,
+        x: f32,
+        y: f32,
+        z: f32,
+        /// 0 while depleted
+        health: u32,
+        max_health: u32,
+    };
+
+    pub const ItemStack = extern struct {
+        item_id: u32,
+        quantity: u32,
+    };
+
+    pub const Recipe = extern struct {
+        pub const Station = enum(u8) { none = 0, workbench = 1, furnace = 2 };
+
+        recipe_id: u32,
+        craft_time_ms: u32,
+        station: Station,
+        input_count: u32,
+        inputs: [4]ItemStack,
+        output_count: u32,
+        outputs: [4]ItemStack,
+        name: [32]u8,
+    };
+
+    pub const Trade = extern struct {
+        trade_id: u32,
+        /// 0 if they left our room
+        partner_entity_id: u32,
+        partner_name: [32]u8,
+        our_count: u32,
+        our_offer: [8]ItemStack,
+        their_count: u32,
+        their_offer: [8]ItemStack,
+        our_coins: u64,
+        their_coins: u64,
+        we_accepted: bool,
+        they_accepted: bool,
+    };
+
+    pub const LedgerEntry = extern struct {
+        pub const Reason = enum(u8) { loot = 0, trade = 1, purchase = 2, transfer = 3, admin = 4 };
+
+        /// Positive for money in, negative for money out
+        amount: i64,
+        balance_after: u64,
+        reason: Reason,
+    };
+
+    pub const CraftJob = extern struct {
+        recipe_id: u32,
+        seconds_left: f32,
+    };
+
+    pub const ItemDrop = extern struct {
+        entity_id: u32,
+        item_id: u32,
+        quantity: u32,
+        x: f32,
+        y: f32,
+        z: f32,
+    };
+
+    pub const Projectile = extern struct {
+        entity_id: u32,
+        x: f32,
+        y: f32,
+        z: f32,
     };
 
     pub const c = struct {
-        pub extern fn connect_to_db_ffi() callconv(.c) ?*Connection;
+        pub extern fn connect_to_db_ffi(resume_session: bool) callconv(.c) ?*Connection;
         pub extern fn free_db_connection(connection: ?*Connection) callconv(.c) void;
+        pub extern fn db_frame_tick_ffi(connection: ?*Connection) callconv(.c) bool;
+        pub extern fn player_move_ffi(connection: ?*Connection, x: f32, y: f32, z: f32) callconv(.c) bool;
+        pub extern fn poll_reducer_error_ffi(out: *ReducerError) callconv(.c) bool;
+        pub extern fn set_player_name_ffi(connection: ?*Connection, name: [*:0]const u8) callconv(.c) bool;
+        pub extern fn send_chat_message_ffi(connection: ?*Connection, channel: ChatMessage.Channel, text: [*:0]const u8) callconv(.c) bool;
+        pub extern fn poll_chat_message_ffi(out: *ChatMessage) callconv(.c) bool;
+        pub extern fn player_fire_ffi(connection: ?*Connection, x: f32, y: f32, z: f32) callconv(.c) bool;
+        pub extern fn player_attack_ffi(connection: ?*Connection, kind: AttackKind, x: f32, y: f32, z: f32) callconv(.c) bool;
+        pub extern fn player_gather_ffi(connection: ?*Connection, node_id: u32) callconv(.c) bool;
+        pub extern fn resource_nodes_ffi(connection: ?*Connection, out: [*]ResourceNode, max: usize) callconv(.c) usize;
+        pub extern fn world_clock_ffi(connection: ?*Connection, out: *WorldClock) callconv(.c) bool;
+        pub extern fn terrain_height_ffi(connection: ?*Connection, x: f32, z: f32) callconv(.c) f32;
+        pub extern fn item_drops_ffi(connection: ?*Connection, out: [*]ItemDrop, max: usize) callconv(.c) usize;
+        pub extern fn npcs_ffi(connection: ?*Connection, out: [*]Npc, max: usize) callconv(.c) usize;
+        pub extern fn projectile_positions_ffi(connection: ?*Connection, out: [*]Projectile, max: usize) callconv(.c) usize;
+        pub extern fn join_team_ffi(connection: ?*Connection, team_id: u32) callconv(.c) bool;
+        pub extern fn local_team_ffi(connection: ?*Connection, out: *Team) callconv(.c) bool;
+        pub extern fn create_room_ffi(connection: ?*Connection, name: [*:0]const u8, max_players: u32) callconv(.c) bool;
+        pub extern fn join_room_ffi(connection: ?*Connection, room_id: u32) callconv(.c) bool;
+        pub extern fn leave_room_ffi(connection: ?*Connection) callconv(.c) bool;
+        pub extern fn current_room_ffi(connection: ?*Connection) callconv(.c) u32;
+        pub extern fn rooms_ffi(connection: ?*Connection, out: [*]Room, max: usize) callconv(.c) usize;
+        pub extern fn leaderboard_ffi(connection: ?*Connection, out: [*]ScoreEntry, max: usize) callconv(.c) usize;
+        pub extern fn scoreboard_ffi(connection: ?*Connection, out: [*]ScoreEntry, max: usize) callconv(.c) usize;
+        pub extern fn local_stats_ffi(connection: ?*Connection, out: *ScoreEntry) callconv(.c) bool;
+        pub extern fn match_state_ffi(connection: ?*Connection, out: *MatchState) callconv(.c) bool;
+        pub extern fn teammates_ffi(connection: ?*Connection, out: [*]Teammate, max: usize) callconv(.c) usize;
+        pub extern fn poll_chunk_update_ffi(out: *Chunk) callconv(.c) bool;
+        pub extern fn chunk_blocks_ffi(connection: ?*Connection, cx: i32, cy: i32, cz: i32, out: *Chunk.Blocks, len: usize) callconv(.c) bool;
+        pub extern fn block_at_ffi(connection: ?*Connection, x: i32, y: i32, z: i32) callconv(.c) u8;
+        pub extern fn place_block_ffi(connection: ?*Connection, x: i32, y: i32, z: i32, block: Block) callconv(.c) bool;
+        pub extern fn break_block_ffi(connection: ?*Connection, x: i32, y: i32, z: i32) callconv(.c) bool;
+        pub extern fn inventory_ffi(connection: ?*Connection, out: [*]InventorySlot, max: usize) callconv(.c) usize;
+        pub extern fn poll_inventory_event_ffi(connection: ?*Connection, out: *InventorySlot) callconv(.c) bool;
+        pub extern fn item_definition_ffi(connection: ?*Connection, item_id: u32, out: *ItemDefinition) callconv(.c) bool;
+        pub extern fn move_item_ffi(connection: ?*Connection, from: u32, to: u32) callconv(.c) bool;
+        pub extern fn split_item_ffi(connection: ?*Connection, from: u32, to: u32, quantity: u32) callconv(.c) bool;
+        pub extern fn drop_item_ffi(connection: ?*Connection, slot: u32, quantity: u32) callconv(.c) bool;
+        pub extern fn use_item_ffi(connection: ?*Connection, slot: u32) callconv(.c) bool;
+        pub extern fn recipes_ffi(connection: ?*Connection, out: [*]Recipe, max: usize) callconv(.c) usize;
+        pub extern fn craft_jobs_ffi(connection: ?*Connection, out: [*]CraftJob, max: usize) callconv(.c) usize;
+        pub extern fn craft_ffi(connection: ?*Connection, recipe_id: u32) callconv(.c) bool;
+        pub extern fn current_trade_ffi(connection: ?*Connection, out: *Trade) callconv(.c) bool;
+        pub extern fn propose_trade_ffi(connection: ?*Connection, entity_id: u32) callconv(.c) bool;
+        pub extern fn update_offer_ffi(connection: ?*Connection, trade_id: u32, stacks: [*]const ItemStack, count: usize, coins: u64) callconv(.c) bool;
+        pub extern fn accept_trade_ffi(connection: ?*Connection, trade_id: u32) callconv(.c) bool;
+        pub extern fn cancel_trade_ffi(connection: ?*Connection, trade_id: u32) callconv(.c) bool;
+        pub extern fn balance_ffi(connection: ?*Connection) callconv(.c) u64;
+        pub extern fn ledger_entries_ffi(connection: ?*Connection, out: [*]LedgerEntry, max: usize) callconv(.c) usize;
+        pub extern fn transfer_ffi(connection: ?*Connection, entity_id: u32, amount: u64) callconv(.c) bool;
     };
 };
 
@@ -25,7 +456,7 @@ pub fn main() !void {
     var buffer: [4096 * 4 + 2]u8 = undefined;
     var fba = std.heap.FixedBufferAllocator.init(&buffer);
     const allocator = fba.allocator();
-    const connection: *db.Connection = try .connect();
+    const connection: *db.Connection = try .connect(true);
     defer connection.disconnect();
 
     const ecs: Ecs = try .init(allocator);
@@ -4,6 +4,9 @@
 #![allow(unused, clippy::all)]
 use spacetimedb_sdk::__codegen::{self as __sdk, __lib, __sats, __ws};
 
+use super::attack_command_type::AttackCommand;
+use super::fire_command_type::FireCommand;
+use super::gather_command_type::GatherCommand;
 use super::move_command_type::MoveCommand;
 
 #[derive(__lib::ser::Serialize, __lib::de::Deserialize, Clone, PartialEq, Debug)]
@@ -12,6 +15,12 @@ pub enum Command {
     Move(MoveCommand),
 
     Jump,
+
+    Fire(FireCommand),
+
+    Attack(AttackCommand),
+
+    Gather(GatherCommand),
 }
 
 impl __sdk::InModule for Command {
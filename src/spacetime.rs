@@ -1,12 +1,24 @@
+// Every `*_ffi` function is a C entry point, pointers coming from Zig are trusted to be valid
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
 mod module_bindings;
-use std::io::Write;
-use std::ptr::{null, null_mut};
+// Same generator as the server, so both agree on the ground
+#[path = "../spacetime/server/src/noise.rs"]
+mod noise;
+// Chunk layout and compression, also shared with the server. Generation is server only.
+#[path = "../spacetime/server/src/voxel.rs"]
+#[allow(dead_code)]
+mod voxel;
+use std::collections::VecDeque;
+use std::ffi::{c_char, CStr};
 use std::os::raw::c_void;
-use std::time::Instant;
+use std::sync::Mutex;
 
 use module_bindings::*;
 
-use spacetimedb_sdk::{credentials, DbContext, Error, Event, Identity, Status, Table, TableWithPrimaryKey};
+use spacetimedb_sdk::{credentials, DbContext, Error, Event, Identity, Status, Table, TableWithPrimaryKey, Timestamp};
+// The bindings export a `SubscriptionHandle` struct too, only the trait's methods are needed
+use spacetimedb_sdk::SubscriptionHandle as _;
 
 
 /// The URI of the SpacetimeDB instance hosting our chat database and module.
@@ -17,10 +29,12 @@ const HOST: &str = "http://localhost:3000";
 const DB_NAME: &str = "zigma";
 
 
+/// With `resume` set we reconnect with the saved token, so the server hands back
+/// the same identity and its player profile (name, position, stats).
 #[unsafe(no_mangle)]
-pub extern "C" fn connect_to_db_ffi() -> *mut c_void {
+pub extern "C" fn connect_to_db_ffi(resume: bool) -> *mut c_void {
     // Create the Rust DbConnection
-    let conn = connect_to_db();
+    let conn = connect_to_db(resume);
 
     // Box it and leak it so we can return a pointer
     Box::into_raw(Box::new(conn)) as *mut c_void
@@ -31,16 +45,1386 @@ pub extern "C" fn free_db_connection(ptr: *mut c_void) {
     if !ptr.is_null() {
         unsafe {
             // Recover the Box and drop it
-            Box::from_raw(ptr as *mut DbConnection);
+            drop(Box::from_raw(ptr as *mut DbConnection));
         }
     }
 }
 
 
+/// Borrow the connection behind a pointer handed out by `connect_to_db_ffi`.
+fn conn_from_ptr<'a>(ptr: *mut c_void) -> Option<&'a DbConnection> {
+    unsafe { (ptr as *mut DbConnection).as_ref() }
+}
+
+/// Process all pending messages and run callbacks. Call once per frame.
+#[unsafe(no_mangle)]
+pub extern "C" fn db_frame_tick_ffi(ptr: *mut c_void) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.frame_tick().is_ok()
+}
+
+/// Ask the server to move the local player. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn player_move_ffi(ptr: *mut c_void, x: f32, y: f32, z: f32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    let cmd = Command::Move(MoveCommand { direction: DbVector3 { x, y, z } });
+    conn.reducers.player_command(cmd).is_ok()
+}
+
+/// Ask the server to fire a projectile from the local player towards `(x, y, z)`.
+/// Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn player_fire_ffi(ptr: *mut c_void, x: f32, y: f32, z: f32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    let cmd = Command::Fire(FireCommand { direction: DbVector3 { x, y, z } });
+    conn.reducers.player_command(cmd).is_ok()
+}
+
+/// Server time of the latest transform update we received, what the player is looking at.
+static LAST_SNAPSHOT_AT: Mutex<Option<Timestamp>> = Mutex::new(None);
+
+fn on_transform_updated(ctx: &EventContext, _old: &Transform, new: &Transform) {
+    if let Event::Reducer(event) = &ctx.event {
+        *LAST_SNAPSHOT_AT.lock().unwrap() = Some(event.timestamp);
+    }
+    update_chunk_view(ctx, new);
+}
+
+/// Attack instantly towards `(x, y, z)`. `kind` is 0 for melee and 1 for hitscan.
+/// The server checks the hit against where other players were in the latest state we received.
+/// Returns false if `kind` is unknown or the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn player_attack_ffi(ptr: *mut c_void, kind: u8, x: f32, y: f32, z: f32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    let kind = match kind {
+        0 => AttackKind::Melee,
+        1 => AttackKind::Hitscan,
+        _ => return false,
+    };
+    let seen_at = LAST_SNAPSHOT_AT.lock().unwrap().unwrap_or_else(Timestamp::now);
+    let cmd = Command::Attack(AttackCommand { kind, direction: DbVector3 { x, y, z }, seen_at });
+    conn.reducers.player_command(cmd).is_ok()
+}
+
+/// Gather from resource node `node_id`, which must be in reach of the local player.
+/// Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn player_gather_ffi(ptr: *mut c_void, node_id: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    let cmd = Command::Gather(GatherCommand { node_id });
+    conn.reducers.player_command(cmd).is_ok()
+}
+
+/// A harvestable tree or ore vein. `health` 0 means depleted until it respawns.
+#[repr(C)]
+pub struct FfiResourceNode {
+    pub node_id: u32,
+    /// 0 tree, 1 ore
+    pub kind: u8,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    pub health: u32,
+    pub max_health: u32,
+}
+
+/// Copy up to `max` resource nodes into `out`. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn resource_nodes_ffi(ptr: *mut c_void, out: *mut FfiResourceNode, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let mut written = 0;
+    for (slot, node) in out.iter_mut().zip(conn.db.resource_node().iter()) {
+        *slot = FfiResourceNode {
+            node_id: node.node_id,
+            kind: match node.kind {
+                ResourceKind::Tree => 0,
+                ResourceKind::Ore => 1,
+            },
+            x: node.position.x,
+            y: node.position.y,
+            z: node.position.z,
+            health: node.health,
+            max_health: node.max_health,
+        };
+        written += 1;
+    }
+    written
+}
+
+/// A projectile in flight as seen by the client cache.
+#[repr(C)]
+pub struct FfiProjectile {
+    pub entity_id: u32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// Copy up to `max` projectiles currently in flight into `out`. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn projectile_positions_ffi(ptr: *mut c_void, out: *mut FfiProjectile, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let projectiles = conn.db.projectile();
+    let transforms = conn.db.transform();
+    let positions = projectiles.iter().filter_map(|p| transforms.entity_id().find(&p.entity_id));
+    let mut written = 0;
+    for (slot, transform) in out.iter_mut().zip(positions) {
+        *slot = FfiProjectile {
+            entity_id: transform.entity_id,
+            x: transform.position.x,
+            y: transform.position.y,
+            z: transform.position.z,
+        };
+        written += 1;
+    }
+    written
+}
+
+/// A server driven character as seen by the client cache.
+#[repr(C)]
+pub struct FfiNpc {
+    pub entity_id: u32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    /// 0 idle, 1 wandering, 2 patrolling, 3 chasing, 4 fleeing
+    pub state: u8,
+}
+
+/// Copy up to `max` NPCs of our room into `out`. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn npcs_ffi(ptr: *mut c_void, out: *mut FfiNpc, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let room_id = local_room(conn);
+    let (npcs, entities, transforms) = (conn.db.npc(), conn.db.entity(), conn.db.transform());
+    let npcs = npcs.iter().filter_map(|npc| {
+        entities.entity_id().find(&npc.entity_id).filter(|e| e.room_id == room_id)?;
+        let transform = transforms.entity_id().find(&npc.entity_id)?;
+        Some((npc, transform))
+    });
+    let mut written = 0;
+    for (slot, (npc, transform)) in out.iter_mut().zip(npcs) {
+        *slot = FfiNpc {
+            entity_id: npc.entity_id,
+            x: transform.position.x,
+            y: transform.position.y,
+            z: transform.position.z,
+            state: match npc.state {
+                NpcState::Idle => 0,
+                NpcState::Wandering => 1,
+                NpcState::Patrolling => 2,
+                NpcState::Chasing => 3,
+                NpcState::Fleeing => 4,
+            },
+        };
+        written += 1;
+    }
+    written
+}
+
+/// A stack of items lying in the world, picked up by walking over it.
+#[repr(C)]
+pub struct FfiItemDrop {
+    pub entity_id: u32,
+    pub item_id: u32,
+    pub quantity: u32,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// Copy up to `max` item drops of our room into `out`. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn item_drops_ffi(ptr: *mut c_void, out: *mut FfiItemDrop, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let room_id = local_room(conn);
+    let (drops, entities, transforms) = (conn.db.item_drop(), conn.db.entity(), conn.db.transform());
+    let drops = drops.iter().filter_map(|drop| {
+        entities.entity_id().find(&drop.entity_id).filter(|e| e.room_id == room_id)?;
+        let transform = transforms.entity_id().find(&drop.entity_id)?;
+        Some((drop, transform))
+    });
+    let mut written = 0;
+    for (slot, (drop, transform)) in out.iter_mut().zip(drops) {
+        *slot = FfiItemDrop {
+            entity_id: drop.entity_id,
+            item_id: drop.item_id,
+            quantity: drop.quantity,
+            x: transform.position.x,
+            y: transform.position.y,
+            z: transform.position.z,
+        };
+        written += 1;
+    }
+    written
+}
+
+/// Height of the generated terrain at `(x, z)`, before any blocks were placed or broken.
+/// Returns 0 until the world config has been received.
+#[unsafe(no_mangle)]
+pub extern "C" fn terrain_height_ffi(ptr: *mut c_void, x: f32, z: f32) -> f32 {
+    let Some(config) = conn_from_ptr(ptr).and_then(|conn| conn.db.world_config().iter().next()) else {
+        return 0.0;
+    };
+    noise::height(config.terrain_seed, x, z)
+}
+
+/// Room of the local player, 0 before it has joined one.
+fn local_room(conn: &DbConnection) -> u32 {
+    let Some(identity) = conn.try_identity() else {
+        return 0;
+    };
+    conn.db.player().identity().find(&identity).map_or(0, |player| player.room_id)
+}
+
+/// Chunks around the local player we keep in the cache, in chunks.
+const CHUNK_VIEW_RADIUS: i32 = 2;
+
+/// Room and chunk column a chunk subscription is centered on.
+type ChunkCenter = (u32, i32, i32);
+
+/// Subscription to the chunks around the local player, with the room and chunk column it is centered on.
+static CHUNK_SUBSCRIPTION: Mutex<Option<(ChunkCenter, SubscriptionHandle)>> = Mutex::new(None);
+
+/// Chunks that were loaded or changed, waiting to be picked up by `poll_chunk_update_ffi`.
+static CHUNK_UPDATES: Mutex<VecDeque<(i32, i32, i32)>> = Mutex::new(VecDeque::new());
+
+/// Move the chunk subscription along when the local player walks into another chunk column or room.
+fn update_chunk_view(ctx: &EventContext, transform: &Transform) {
+    let Some(player) = ctx.db.player().identity().find(&ctx.identity()) else {
+        return;
+    };
+    if player.entity_id != transform.entity_id {
+        return;
+    }
+    let (cx, _, cz) = voxel::chunk_of(
+        transform.position.x.floor() as i32,
+        transform.position.y.floor() as i32,
+        transform.position.z.floor() as i32,
+    );
+    let mut current = CHUNK_SUBSCRIPTION.lock().unwrap();
+    if current.as_ref().is_some_and(|(center, _)| *center == (player.room_id, cx, cz)) {
+        return;
+    }
+    if let Some((_, handle)) = current.take()
+        && let Err(e) = handle.unsubscribe()
+    {
+        eprintln!("Failed to leave chunk subscription: {:?}", e);
+    }
+    let query = format!(
+        "SELECT * FROM chunk WHERE room_id = {} AND cx >= {} AND cx <= {} AND cz >= {} AND cz <= {}",
+        player.room_id,
+        cx - CHUNK_VIEW_RADIUS,
+        cx + CHUNK_VIEW_RADIUS,
+        cz - CHUNK_VIEW_RADIUS,
+        cz + CHUNK_VIEW_RADIUS,
+    );
+    let handle = ctx.subscription_builder().on_error(on_sub_error).subscribe(vec![query]);
+    *current = Some(((player.room_id, cx, cz), handle));
+}
+
+fn on_chunk_changed(_ctx: &EventContext, chunk: &Chunk) {
+    CHUNK_UPDATES.lock().unwrap().push_back((chunk.cx, chunk.cy, chunk.cz));
+}
+
+#[repr(C)]
+pub struct FfiChunkCoord {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+}
+
+/// Pop the oldest loaded or changed chunk into `out`, so its mesh can be rebuilt.
+/// Returns false when there are none.
+#[unsafe(no_mangle)]
+pub extern "C" fn poll_chunk_update_ffi(out: *mut FfiChunkCoord) -> bool {
+    let Some(out) = (unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let Some((x, y, z)) = CHUNK_UPDATES.lock().unwrap().pop_front() else {
+        return false;
+    };
+    *out = FfiChunkCoord { x, y, z };
+    true
+}
+
+/// Copy the blocks of a loaded chunk into `out`, which must hold `len` bytes.
+/// Blocks are ordered x first, then z, then y. Returns false if the chunk is not loaded
+/// or `out` is smaller than a chunk.
+#[unsafe(no_mangle)]
+pub extern "C" fn chunk_blocks_ffi(ptr: *mut c_void, cx: i32, cy: i32, cz: i32, out: *mut u8, len: usize) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    if out.is_null() || len < voxel::CHUNK_VOLUME {
+        return false;
+    }
+    let Some(chunk) = conn.db.chunk().key().find(&voxel::chunk_key(local_room(conn), cx, cy, cz)) else {
+        return false;
+    };
+    let out = unsafe { std::slice::from_raw_parts_mut(out, voxel::CHUNK_VOLUME) };
+    out.copy_from_slice(&voxel::decompress(&chunk.data));
+    true
+}
+
+/// Block at world `(x, y, z)`, 0 for air. Returns 255 if its chunk is not loaded.
+#[unsafe(no_mangle)]
+pub extern "C" fn block_at_ffi(ptr: *mut c_void, x: i32, y: i32, z: i32) -> u8 {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return u8::MAX;
+    };
+    let (cx, cy, cz) = voxel::chunk_of(x, y, z);
+    let Some(chunk) = conn.db.chunk().key().find(&voxel::chunk_key(local_room(conn), cx, cy, cz)) else {
+        return u8::MAX;
+    };
+    voxel::decompress(&chunk.data)[voxel::local_index(x, y, z)]
+}
+
+/// Place `block` at world `(x, y, z)`. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn place_block_ffi(ptr: *mut c_void, x: i32, y: i32, z: i32, block: u8) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.place_block(x, y, z, block).is_ok()
+}
+
+/// Break the block at world `(x, y, z)`. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn break_block_ffi(ptr: *mut c_void, x: i32, y: i32, z: i32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.break_block(x, y, z).is_ok()
+}
+
+/// Inventory slots of the local player that changed, waiting to be picked up by `poll_inventory_event_ffi`.
+static INVENTORY_EVENTS: Mutex<VecDeque<u32>> = Mutex::new(VecDeque::new());
+
+fn on_inventory_changed(ctx: &EventContext, slots: &[u32], owner: Identity) {
+    if owner != ctx.identity() {
+        return;
+    }
+    let mut events = INVENTORY_EVENTS.lock().unwrap();
+    for slot in slots {
+        if !events.contains(slot) {
+            events.push_back(*slot);
+        }
+    }
+}
+
+#[repr(C)]
+pub struct FfiInventorySlot {
+    pub slot: u32,
+    /// 0 for an empty slot
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+fn inventory_slot_of(conn: &DbConnection, slot: u32) -> FfiInventorySlot {
+    let identity = conn.try_identity();
+    let stack = conn
+        .db
+        .inventory_slot()
+        .iter()
+        .find(|s| Some(s.owner) == identity && s.slot == slot);
+    FfiInventorySlot {
+        slot,
+        item_id: stack.as_ref().map_or(0, |s| s.item_id),
+        quantity: stack.as_ref().map_or(0, |s| s.quantity),
+    }
+}
+
+/// Pop the oldest changed inventory slot into `out`, with what it holds now.
+/// Returns false when there are none.
+#[unsafe(no_mangle)]
+pub extern "C" fn poll_inventory_event_ffi(ptr: *mut c_void, out: *mut FfiInventorySlot) -> bool {
+    let (Some(conn), Some(out)) = (conn_from_ptr(ptr), unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let Some(slot) = INVENTORY_EVENTS.lock().unwrap().pop_front() else {
+        return false;
+    };
+    *out = inventory_slot_of(conn, slot);
+    true
+}
+
+/// Copy the non-empty slots of the local player's inventory into `out`. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn inventory_ffi(ptr: *mut c_void, out: *mut FfiInventorySlot, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let Some(identity) = conn.try_identity() else {
+        return 0;
+    };
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let mut stacks: Vec<InventorySlot> = conn.db.inventory_slot().iter().filter(|s| s.owner == identity).collect();
+    stacks.sort_by_key(|s| s.slot);
+    let mut written = 0;
+    for (slot, stack) in out.iter_mut().zip(stacks) {
+        *slot = FfiInventorySlot { slot: stack.slot, item_id: stack.item_id, quantity: stack.quantity };
+        written += 1;
+    }
+    written
+}
+
+#[repr(C)]
+pub struct FfiItemDefinition {
+    pub item_id: u32,
+    pub max_stack: u32,
+    /// 0 material, 1 block, 2 food
+    pub kind: u8,
+    /// Block id for blocks, health restored for food
+    pub value: f32,
+    pub name: [u8; FFI_PLAYER_NAME_LEN],
+}
+
+/// Fill `out` with the definition of `item_id`. Returns false if there is no such item.
+#[unsafe(no_mangle)]
+pub extern "C" fn item_definition_ffi(ptr: *mut c_void, item_id: u32, out: *mut FfiItemDefinition) -> bool {
+    let (Some(conn), Some(out)) = (conn_from_ptr(ptr), unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let Some(item) = conn.db.item_definition().item_id().find(&item_id) else {
+        return false;
+    };
+    out.item_id = item.item_id;
+    out.max_stack = item.max_stack;
+    (out.kind, out.value) = match item.kind {
+        ItemKind::Material => (0, 0.0),
+        ItemKind::Block(block) => (1, block as f32),
+        ItemKind::Food(heal) => (2, heal),
+    };
+    write_c_str(&item.name, &mut out.name);
+    true
+}
+
+/// Move the stack in slot `from` onto slot `to`. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn move_item_ffi(ptr: *mut c_void, from: u32, to: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.move_item(from, to).is_ok()
+}
+
+/// Move `quantity` items from slot `from` into the empty slot `to`.
+/// Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn split_item_ffi(ptr: *mut c_void, from: u32, to: u32, quantity: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.split_item(from, to, quantity).is_ok()
+}
+
+/// Drop `quantity` items out of `slot`. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn drop_item_ffi(ptr: *mut c_void, slot: u32, quantity: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.drop_item(slot, quantity).is_ok()
+}
+
+/// Use one item out of `slot`. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn use_item_ffi(ptr: *mut c_void, slot: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.use_item(slot).is_ok()
+}
+
+/// Most inputs or outputs a recipe can show over FFI.
+const FFI_RECIPE_STACKS: usize = 4;
+
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+pub struct FfiItemStack {
+    pub item_id: u32,
+    pub quantity: u32,
+}
+
+#[repr(C)]
+pub struct FfiRecipe {
+    pub recipe_id: u32,
+    pub craft_time_ms: u32,
+    /// 0 anywhere, 1 workbench, 2 furnace
+    pub station: u8,
+    pub input_count: u32,
+    pub inputs: [FfiItemStack; FFI_RECIPE_STACKS],
+    pub output_count: u32,
+    pub outputs: [FfiItemStack; FFI_RECIPE_STACKS],
+    pub name: [u8; FFI_PLAYER_NAME_LEN],
+}
+
+fn write_item_stacks<const N: usize>(stacks: &[ItemStack], out: &mut [FfiItemStack; N]) -> u32 {
+    *out = [FfiItemStack::default(); N];
+    for (slot, stack) in out.iter_mut().zip(stacks) {
+        *slot = FfiItemStack { item_id: stack.item_id, quantity: stack.quantity };
+    }
+    stacks.len().min(N) as u32
+}
+
+/// Copy up to `max` recipes into `out`. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn recipes_ffi(ptr: *mut c_void, out: *mut FfiRecipe, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let mut recipes: Vec<Recipe> = conn.db.recipe().iter().collect();
+    recipes.sort_by_key(|r| r.recipe_id);
+    let mut written = 0;
+    for (slot, recipe) in out.iter_mut().zip(recipes) {
+        slot.recipe_id = recipe.recipe_id;
+        slot.craft_time_ms = recipe.craft_time_ms;
+        slot.station = match recipe.station {
+            None => 0,
+            Some(StationKind::Workbench) => 1,
+            Some(StationKind::Furnace) => 2,
+        };
+        slot.input_count = write_item_stacks(&recipe.inputs, &mut slot.inputs);
+        slot.output_count = write_item_stacks(&recipe.outputs, &mut slot.outputs);
+        write_c_str(&recipe.name, &mut slot.name);
+        written += 1;
+    }
+    written
+}
+
+#[repr(C)]
+pub struct FfiCraftJob {
+    pub recipe_id: u32,
+    /// Until the outputs arrive, by our clock
+    pub seconds_left: f32,
+}
+
+/// Copy the local player's pending crafts into `out`, soonest first. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn craft_jobs_ffi(ptr: *mut c_void, out: *mut FfiCraftJob, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let Some(identity) = conn.try_identity() else {
+        return 0;
+    };
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let mut jobs: Vec<CraftJob> = conn.db.craft_job().iter().filter(|j| j.owner == identity).collect();
+    jobs.sort_by_key(|j| j.ready_at);
+    let now = Timestamp::now().to_micros_since_unix_epoch();
+    let mut written = 0;
+    for (slot, job) in out.iter_mut().zip(jobs) {
+        let left_micros = job.ready_at.to_micros_since_unix_epoch() - now;
+        *slot = FfiCraftJob { recipe_id: job.recipe_id, seconds_left: left_micros.max(0) as f32 / 1_000_000.0 };
+        written += 1;
+    }
+    written
+}
+
+/// Craft `recipe_id` with items from the local player's inventory.
+/// Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn craft_ffi(ptr: *mut c_void, recipe_id: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.craft(recipe_id).is_ok()
+}
+
+/// Most stacks one side of a trade can show over FFI, matches the server's limit.
+const FFI_TRADE_STACKS: usize = 8;
+
+#[repr(C)]
+pub struct FfiTrade {
+    pub trade_id: u32,
+    /// Entity of the other player, 0 if they are not in our room anymore
+    pub partner_entity_id: u32,
+    pub partner_name: [u8; FFI_PLAYER_NAME_LEN],
+    pub our_count: u32,
+    pub our_offer: [FfiItemStack; FFI_TRADE_STACKS],
+    pub their_count: u32,
+    pub their_offer: [FfiItemStack; FFI_TRADE_STACKS],
+    pub our_coins: u64,
+    pub their_coins: u64,
+    pub we_accepted: bool,
+    pub they_accepted: bool,
+}
+
+/// Fill `out` with the trade the local player is part of. Returns false when not trading.
+#[unsafe(no_mangle)]
+pub extern "C" fn current_trade_ffi(ptr: *mut c_void, out: *mut FfiTrade) -> bool {
+    let (Some(conn), Some(out)) = (conn_from_ptr(ptr), unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let Some(me) = conn.try_identity() else {
+        return false;
+    };
+    let Some(trade) = conn.db.trade().iter().find(|t| t.proposer == me || t.partner == me) else {
+        return false;
+    };
+    let proposing = trade.proposer == me;
+    let (partner, ours, theirs, we_accepted, they_accepted) = if proposing {
+        (trade.partner, &trade.proposer_offer, &trade.partner_offer, trade.proposer_accepted, trade.partner_accepted)
+    } else {
+        (trade.proposer, &trade.partner_offer, &trade.proposer_offer, trade.partner_accepted, trade.proposer_accepted)
+    };
+    (out.our_coins, out.their_coins) = if proposing {
+        (trade.proposer_coins, trade.partner_coins)
+    } else {
+        (trade.partner_coins, trade.proposer_coins)
+    };
+    let partner = conn.db.player().identity().find(&partner);
+    out.trade_id = trade.trade_id;
+    out.partner_entity_id = partner.as_ref().map_or(0, |p| p.entity_id);
+    write_c_str(partner.as_ref().map_or("", |p| p.name.as_str()), &mut out.partner_name);
+    out.our_count = write_item_stacks(ours, &mut out.our_offer);
+    out.their_count = write_item_stacks(theirs, &mut out.their_offer);
+    out.we_accepted = we_accepted;
+    out.they_accepted = they_accepted;
+    true
+}
+
+/// Open a trade with the player controlling `entity_id`, who must be in our room.
+/// Returns false if there is no such player or the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn propose_trade_ffi(ptr: *mut c_void, entity_id: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    let Some(partner) = conn.db.player().iter().find(|p| p.entity_id == entity_id) else {
+        return false;
+    };
+    conn.reducers.propose_trade(partner.identity).is_ok()
+}
+
+/// Replace our side of trade `trade_id` with the `count` stacks at `stacks` and `coins`.
+/// Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn update_offer_ffi(
+    ptr: *mut c_void,
+    trade_id: u32,
+    stacks: *const FfiItemStack,
+    count: usize,
+    coins: u64,
+) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    let stacks = if count == 0 {
+        &[][..]
+    } else if stacks.is_null() {
+        return false;
+    } else {
+        unsafe { std::slice::from_raw_parts(stacks, count) }
+    };
+    let offer = stacks.iter().map(|s| ItemStack { item_id: s.item_id, quantity: s.quantity }).collect();
+    conn.reducers.update_offer(trade_id, offer, coins).is_ok()
+}
+
+/// Accept trade `trade_id` as it stands. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn accept_trade_ffi(ptr: *mut c_void, trade_id: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.accept_trade(trade_id).is_ok()
+}
+
+/// Walk away from trade `trade_id`. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn cancel_trade_ffi(ptr: *mut c_void, trade_id: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.cancel_trade(trade_id).is_ok()
+}
+
+/// Coins the local player holds, 0 before they ever had any.
+#[unsafe(no_mangle)]
+pub extern "C" fn balance_ffi(ptr: *mut c_void) -> u64 {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    let Some(identity) = conn.try_identity() else {
+        return 0;
+    };
+    conn.db.wallet().identity().find(&identity).map_or(0, |w| w.balance)
+}
+
+#[repr(C)]
+pub struct FfiLedgerEntry {
+    /// Positive for money in, negative for money out
+    pub amount: i64,
+    pub balance_after: u64,
+    /// 0 loot, 1 trade, 2 purchase, 3 transfer, 4 admin
+    pub reason: u8,
+}
+
+/// Copy the local player's latest ledger entries into `out`, newest first. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn ledger_entries_ffi(ptr: *mut c_void, out: *mut FfiLedgerEntry, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let Some(identity) = conn.try_identity() else {
+        return 0;
+    };
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let mut rows: Vec<LedgerEntry> = conn.db.ledger_entry().iter().filter(|t| t.identity == identity).collect();
+    rows.sort_by_key(|t| std::cmp::Reverse(t.entry_id));
+    let mut written = 0;
+    for (slot, row) in out.iter_mut().zip(rows) {
+        *slot = FfiLedgerEntry {
+            amount: row.amount,
+            balance_after: row.balance_after,
+            reason: match row.reason {
+                LedgerReason::Loot => 0,
+                LedgerReason::Trade => 1,
+                LedgerReason::Purchase => 2,
+                LedgerReason::Transfer => 3,
+                LedgerReason::Admin => 4,
+            },
+        };
+        written += 1;
+    }
+    written
+}
+
+/// Send `amount` coins to the player controlling `entity_id`.
+/// Returns false if there is no such player in our room or the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn transfer_ffi(ptr: *mut c_void, entity_id: u32, amount: u64) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    let Some(to) = conn.db.player().iter().find(|p| p.entity_id == entity_id) else {
+        return false;
+    };
+    conn.reducers.transfer(to.identity, amount).is_ok()
+}
+
+#[repr(C)]
+pub struct FfiWorldClock {
+    /// Days since the world was created
+    pub day: u32,
+    /// Fraction of the day in [0, 1), 0 is midnight and 0.5 is noon
+    pub time_of_day: f32,
+    /// Real seconds a full day lasts
+    pub day_length_secs: u32,
+}
+
+/// Fill `out` with the world's time of day, moved along by our clock since the last update
+/// so lighting stays smooth. Returns false until the clock has been received.
+#[unsafe(no_mangle)]
+pub extern "C" fn world_clock_ffi(ptr: *mut c_void, out: *mut FfiWorldClock) -> bool {
+    let (Some(conn), Some(out)) = (conn_from_ptr(ptr), unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let (Some(clock), Some(config)) = (conn.db.world_clock().iter().next(), conn.db.world_config().iter().next()) else {
+        return false;
+    };
+    let day_length_secs = config.day_length_secs.max(1);
+    let since_micros = Timestamp::now().to_micros_since_unix_epoch() - clock.updated_at.to_micros_since_unix_epoch();
+    let time = clock.time_of_day + since_micros.max(0) as f32 / 1_000_000.0 / day_length_secs as f32;
+    out.day = clock.day + time as u32;
+    out.time_of_day = time.fract();
+    out.day_length_secs = day_length_secs;
+    true
+}
+
+/// Name to send with `set_name` as soon as the connection is up.
+static PENDING_NAME: Mutex<Option<String>> = Mutex::new(None);
+
+/// Choose a player name. With a null connection the name is kept and sent once connected,
+/// otherwise it is sent right away. Returns false if `name` is not valid UTF-8 or sending failed.
+#[unsafe(no_mangle)]
+pub extern "C" fn set_player_name_ffi(ptr: *mut c_void, name: *const c_char) -> bool {
+    if name.is_null() {
+        return false;
+    }
+    let Ok(name) = unsafe { CStr::from_ptr(name) }.to_str() else {
+        return false;
+    };
+    match conn_from_ptr(ptr) {
+        Some(conn) => conn.reducers.set_name(name.to_string()).is_ok(),
+        None => {
+            *PENDING_NAME.lock().unwrap() = Some(name.to_string());
+            true
+        }
+    }
+}
+
+/// Error returned by one of our reducer calls, parsed from the server's `Code: detail` string.
+/// Mirrors `ReducerError` in the server module.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReducerError {
+    NotConnected,
+    Dead,
+    InvalidInput(String),
+    RateLimited,
+    PermissionDenied(String),
+    NotFound(String),
+    Internal(String),
+    Unknown(String),
+}
+
+impl ReducerError {
+    pub fn parse(msg: &str) -> Self {
+        let (code, detail) = match msg.split_once(": ") {
+            Some((code, detail)) => (code, detail.to_string()),
+            None => (msg, String::new()),
+        };
+        match code {
+            "NotConnected" => ReducerError::NotConnected,
+            "Dead" => ReducerError::Dead,
+            "InvalidInput" => ReducerError::InvalidInput(detail),
+            "RateLimited" => ReducerError::RateLimited,
+            "PermissionDenied" => ReducerError::PermissionDenied(detail),
+            "NotFound" => ReducerError::NotFound(detail),
+            "Internal" => ReducerError::Internal(detail),
+            _ => ReducerError::Unknown(msg.to_string()),
+        }
+    }
+
+    fn ffi_code(&self) -> ReducerErrorCode {
+        match self {
+            ReducerError::NotConnected => ReducerErrorCode::NotConnected,
+            ReducerError::Dead => ReducerErrorCode::Dead,
+            ReducerError::InvalidInput(_) => ReducerErrorCode::InvalidInput,
+            ReducerError::RateLimited => ReducerErrorCode::RateLimited,
+            ReducerError::PermissionDenied(_) => ReducerErrorCode::PermissionDenied,
+            ReducerError::NotFound(_) => ReducerErrorCode::NotFound,
+            ReducerError::Internal(_) => ReducerErrorCode::Internal,
+            ReducerError::Unknown(_) => ReducerErrorCode::Unknown,
+        }
+    }
+
+    fn detail(&self) -> &str {
+        match self {
+            ReducerError::InvalidInput(msg)
+            | ReducerError::PermissionDenied(msg)
+            | ReducerError::NotFound(msg)
+            | ReducerError::Internal(msg)
+            | ReducerError::Unknown(msg) => msg,
+            _ => "",
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReducerErrorCode {
+    Unknown = 0,
+    NotConnected = 1,
+    InvalidInput = 2,
+    RateLimited = 3,
+    Internal = 4,
+    PermissionDenied = 5,
+    NotFound = 6,
+    Dead = 7,
+}
+
+const FFI_MESSAGE_LEN: usize = 128;
+const FFI_REDUCER_NAME_LEN: usize = 32;
+
+#[repr(C)]
+pub struct FfiReducerError {
+    pub code: ReducerErrorCode,
+    // Name of the reducer that failed, null terminated
+    pub reducer: [u8; FFI_REDUCER_NAME_LEN],
+    // Null terminated, truncated to fit
+    pub message: [u8; FFI_MESSAGE_LEN],
+}
+
+/// Failed reducer calls waiting to be picked up by `poll_reducer_error_ffi`.
+static REDUCER_ERRORS: Mutex<VecDeque<(&'static str, ReducerError)>> = Mutex::new(VecDeque::new());
+
+fn push_reducer_error(reducer: &'static str, status: &Status) {
+    if let Status::Failed(msg) = status {
+        eprintln!("Reducer {} failed: {}", reducer, msg);
+        REDUCER_ERRORS.lock().unwrap().push_back((reducer, ReducerError::parse(msg)));
+    }
+}
+
+/// Copy `text` into a fixed C buffer, always leaving room for the null terminator.
+/// Truncates on a char boundary so the result stays valid UTF-8.
+fn write_c_str(text: &str, out: &mut [u8]) {
+    let mut len = text.len().min(out.len() - 1);
+    while !text.is_char_boundary(len) {
+        len -= 1;
+    }
+    out[..len].copy_from_slice(&text.as_bytes()[..len]);
+    out[len] = 0;
+}
+
+/// Pop the oldest reducer error into `out`. Returns false when there are none.
+#[unsafe(no_mangle)]
+pub extern "C" fn poll_reducer_error_ffi(out: *mut FfiReducerError) -> bool {
+    let Some(out) = (unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let Some((reducer, err)) = REDUCER_ERRORS.lock().unwrap().pop_front() else {
+        return false;
+    };
+    out.code = err.ffi_code();
+    write_c_str(reducer, &mut out.reducer);
+    write_c_str(err.detail(), &mut out.message);
+    true
+}
+
+const FFI_PLAYER_NAME_LEN: usize = 32;
+const FFI_CHAT_TEXT_LEN: usize = 1024;
+
+#[repr(C)]
+pub struct FfiChatMessage {
+    // 0 = global, 1 = proximity
+    pub channel: u8,
+    pub sender_name: [u8; FFI_PLAYER_NAME_LEN],
+    pub text: [u8; FFI_CHAT_TEXT_LEN],
+}
+
+/// Chat messages we should display as (channel, sender name, text),
+/// waiting to be picked up by `poll_chat_message_ffi`.
+static CHAT_MESSAGES: Mutex<VecDeque<(ChatChannel, String, String)>> = Mutex::new(VecDeque::new());
+
+fn on_chat_message_inserted(_ctx: &EventContext, message: &ChatMessage) {
+    let entry = (ChatChannel::Global, message.sender_name.clone(), message.text.clone());
+    CHAT_MESSAGES.lock().unwrap().push_back(entry);
+}
+
+/// The server only sends us proximity messages said within range of us.
+fn on_proximity_message_inserted(_ctx: &EventContext, message: &ProximityMessage) {
+    let entry = (ChatChannel::Proximity, message.sender_name.clone(), message.text.clone());
+    CHAT_MESSAGES.lock().unwrap().push_back(entry);
+}
+
+/// Send a chat message. `channel` is 0 for global and 1 for proximity chat.
+#[unsafe(no_mangle)]
+pub extern "C" fn send_chat_message_ffi(ptr: *mut c_void, channel: u8, text: *const c_char) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    if text.is_null() {
+        return false;
+    }
+    let Ok(text) = unsafe { CStr::from_ptr(text) }.to_str() else {
+        return false;
+    };
+    let channel = match channel {
+        0 => ChatChannel::Global,
+        1 => ChatChannel::Proximity,
+        _ => return false,
+    };
+    conn.reducers.send_message(channel, text.to_string()).is_ok()
+}
+
+/// Pop the oldest received chat message into `out`. Returns false when there are none.
+#[unsafe(no_mangle)]
+pub extern "C" fn poll_chat_message_ffi(out: *mut FfiChatMessage) -> bool {
+    let Some(out) = (unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let Some((channel, sender_name, text)) = CHAT_MESSAGES.lock().unwrap().pop_front() else {
+        return false;
+    };
+    out.channel = match channel {
+        ChatChannel::Global => 0,
+        ChatChannel::Proximity => 1,
+    };
+    write_c_str(&sender_name, &mut out.sender_name);
+    write_c_str(&text, &mut out.text);
+    true
+}
+
+/// Switch the local player to `team_id`, 0 lets the server pick the smallest team.
+/// Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn join_team_ffi(ptr: *mut c_void, team_id: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    let team_id = (team_id != 0).then_some(team_id);
+    conn.reducers.join_team(team_id).is_ok()
+}
+
+#[repr(C)]
+pub struct FfiTeam {
+    pub team_id: u32,
+    pub score: u32,
+    pub name: [u8; 32],
+}
+
+#[repr(C)]
+pub struct FfiTeammate {
+    pub entity_id: u32,
+    pub name: [u8; 32],
+}
+
+fn local_team_id(conn: &DbConnection) -> Option<u32> {
+    let me = conn.db.player().identity().find(&conn.try_identity()?)?;
+    conn.db.team_member().entity_id().find(&me.entity_id).map(|m| m.team_id)
+}
+
+/// Fill `out` with the local player's team. Returns false if we are not on a team (yet).
+#[unsafe(no_mangle)]
+pub extern "C" fn local_team_ffi(ptr: *mut c_void, out: *mut FfiTeam) -> bool {
+    let (Some(conn), Some(out)) = (conn_from_ptr(ptr), unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let Some(team) = local_team_id(conn).and_then(|id| conn.db.team().team_id().find(&id)) else {
+        return false;
+    };
+    out.team_id = team.team_id;
+    out.score = team.score;
+    write_c_str(&team.name, &mut out.name);
+    true
+}
+
+/// Copy up to `max` other players on the local player's team into `out`. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn teammates_ffi(ptr: *mut c_void, out: *mut FfiTeammate, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    let Some(team_id) = local_team_id(conn) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let Some(identity) = conn.try_identity() else {
+        return 0;
+    };
+    let players = conn.db.player();
+    let members = conn.db.team_member();
+    let teammates = players
+        .iter()
+        .filter(|p| p.identity != identity)
+        .filter(|p| members.entity_id().find(&p.entity_id).is_some_and(|m| m.team_id == team_id));
+    let mut written = 0;
+    for (slot, player) in out.iter_mut().zip(teammates) {
+        slot.entity_id = player.entity_id;
+        write_c_str(&player.name, &mut slot.name);
+        written += 1;
+    }
+    written
+}
+
+#[repr(C)]
+pub struct FfiMatchState {
+    pub match_id: u32,
+    /// 0 warmup, 1 in progress, 2 ended
+    pub phase: u8,
+    /// Until the phase ends, by our clock
+    pub seconds_left: f32,
+    /// Team that won the last match, 0 for none or a tie
+    pub winner_team: u32,
+}
+
+/// Fill `out` with the current match. Returns false until the match state has been received.
+#[unsafe(no_mangle)]
+pub extern "C" fn match_state_ffi(ptr: *mut c_void, out: *mut FfiMatchState) -> bool {
+    let (Some(conn), Some(out)) = (conn_from_ptr(ptr), unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let room_id = current_room_ffi(ptr);
+    let Some(state) = conn.db.match_state().room_id().find(&room_id) else {
+        return false;
+    };
+    let left_micros = state.phase_ends_at.to_micros_since_unix_epoch() - Timestamp::now().to_micros_since_unix_epoch();
+    out.match_id = state.match_id;
+    out.phase = match state.phase {
+        MatchPhase::Warmup => 0,
+        MatchPhase::InProgress => 1,
+        MatchPhase::Ended => 2,
+    };
+    out.seconds_left = left_micros.max(0) as f32 / 1_000_000.0;
+    out.winner_team = state.winner.unwrap_or(0);
+    true
+}
+
+/// Open a new room named `name` and move into it, `max_players` 0 uses the server default.
+/// Returns false if `name` is not valid UTF-8 or the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn create_room_ffi(ptr: *mut c_void, name: *const c_char, max_players: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    if name.is_null() {
+        return false;
+    }
+    let Ok(name) = unsafe { CStr::from_ptr(name) }.to_str() else {
+        return false;
+    };
+    conn.reducers.create_room(name.to_string(), max_players).is_ok()
+}
+
+/// Move the local player into `room_id`. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn join_room_ffi(ptr: *mut c_void, room_id: u32) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.join_room(room_id).is_ok()
+}
+
+/// Move the local player back to the lobby. Returns false if the request could not be sent.
+#[unsafe(no_mangle)]
+pub extern "C" fn leave_room_ffi(ptr: *mut c_void) -> bool {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return false;
+    };
+    conn.reducers.leave_room().is_ok()
+}
+
+/// Room the local player is in, 0 until our player row has arrived.
+#[unsafe(no_mangle)]
+pub extern "C" fn current_room_ffi(ptr: *mut c_void) -> u32 {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    local_room(conn)
+}
+
+#[repr(C)]
+pub struct FfiRoom {
+    pub room_id: u32,
+    pub max_players: u32,
+    pub name: [u8; 32],
+}
+
+/// Copy up to `max` open rooms into `out`. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn rooms_ffi(ptr: *mut c_void, out: *mut FfiRoom, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    if out.is_null() {
+        return 0;
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let mut written = 0;
+    for (slot, room) in out.iter_mut().zip(conn.db.room().iter()) {
+        slot.room_id = room.room_id;
+        slot.max_players = room.max_players;
+        write_c_str(&room.name, &mut slot.name);
+        written += 1;
+    }
+    written
+}
+
+#[repr(C)]
+pub struct FfiScoreEntry {
+    pub kills: u32,
+    pub deaths: u32,
+    pub score: i64,
+    pub name: [u8; 32],
+}
+
+fn write_score_entries(out: *mut FfiScoreEntry, max: usize, entries: impl Iterator<Item = (String, u32, u32, i64)>) -> usize {
+    if out.is_null() {
+        return 0;
+    }
+    let out = unsafe { std::slice::from_raw_parts_mut(out, max) };
+    let mut written = 0;
+    for (slot, (name, kills, deaths, score)) in out.iter_mut().zip(entries) {
+        slot.kills = kills;
+        slot.deaths = deaths;
+        slot.score = score;
+        write_c_str(&name, &mut slot.name);
+        written += 1;
+    }
+    written
+}
+
+/// Copy up to `max` all-time leaderboard entries into `out`, best first. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn leaderboard_ffi(ptr: *mut c_void, out: *mut FfiScoreEntry, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    let mut entries: Vec<LeaderboardEntry> = conn.db.leaderboard().iter().collect();
+    entries.sort_by_key(|e| e.rank);
+    write_score_entries(out, max, entries.into_iter().map(|e| (e.name, e.kills, e.deaths, e.score)))
+}
+
+/// Copy up to `max` scoreboard rows of the match in our room into `out`, best first. Returns how many were written.
+#[unsafe(no_mangle)]
+pub extern "C" fn scoreboard_ffi(ptr: *mut c_void, out: *mut FfiScoreEntry, max: usize) -> usize {
+    let Some(conn) = conn_from_ptr(ptr) else {
+        return 0;
+    };
+    let room_id = current_room_ffi(ptr);
+    let mut rows: Vec<MatchScore> = conn.db.match_score().iter().filter(|s| s.room_id == room_id).collect();
+    rows.sort_by_key(|s| std::cmp::Reverse(s.score));
+    write_score_entries(out, max, rows.into_iter().map(|s| (s.name, s.kills, s.deaths, s.score)))
+}
+
+/// Fill `out` with the local player's all-time stats. Returns false until they have any.
+#[unsafe(no_mangle)]
+pub extern "C" fn local_stats_ffi(ptr: *mut c_void, out: *mut FfiScoreEntry) -> bool {
+    let (Some(conn), Some(out)) = (conn_from_ptr(ptr), unsafe { out.as_mut() }) else {
+        return false;
+    };
+    let Some(identity) = conn.try_identity() else {
+        return false;
+    };
+    let Some(stats) = conn.db.player_stats().identity().find(&identity) else {
+        return false;
+    };
+    out.kills = stats.kills;
+    out.deaths = stats.deaths;
+    out.score = stats.score;
+    let name = conn.db.player().identity().find(&identity).map(|p| p.name).unwrap_or_default();
+    write_c_str(&name, &mut out.name);
+    true
+}
+
+/// Subscription to everything in the local player's room, replaced whenever they change rooms.
+static ROOM_SUBSCRIPTION: Mutex<Option<(u32, SubscriptionHandle)>> = Mutex::new(None);
+
+/// Queries for the contents of one room. Components are scoped through the entity they belong to.
+fn room_queries(room_id: u32) -> Vec<String> {
+    let component = |table: &str| {
+        format!(
+            "SELECT {table}.* FROM {table} JOIN entity ON {table}.entity_id = entity.entity_id WHERE entity.room_id = {room_id}"
+        )
+    };
+    vec![
+        format!("SELECT * FROM player WHERE room_id = {room_id}"),
+        format!("SELECT * FROM entity WHERE room_id = {room_id}"),
+        component("transform"),
+        component("projectile"),
+        component("team_member"),
+        component("npc"),
+        component("item_drop"),
+        format!("SELECT * FROM team WHERE room_id = {room_id}"),
+        format!("SELECT * FROM match_state WHERE room_id = {room_id}"),
+        format!("SELECT * FROM chat_message WHERE room_id = {room_id}"),
+        format!("SELECT * FROM proximity_message WHERE room_id = {room_id}"),
+        format!("SELECT * FROM match_score WHERE room_id = {room_id}"),
+    ]
+}
+
+fn on_player_changed(ctx: &EventContext, player: &Player) {
+    if player.identity != ctx.identity() {
+        return;
+    }
+    // Chunks are per room, the transform may have arrived before the room changed
+    if let Some(transform) = ctx.db.transform().entity_id().find(&player.entity_id) {
+        update_chunk_view(ctx, &transform);
+    }
+    let mut current = ROOM_SUBSCRIPTION.lock().unwrap();
+    if current.as_ref().is_some_and(|(room_id, _)| *room_id == player.room_id) {
+        return;
+    }
+    if let Some((_, handle)) = current.take()
+        && let Err(e) = handle.unsubscribe()
+    {
+        eprintln!("Failed to leave room subscription: {:?}", e);
+    }
+    let handle = ctx
+        .subscription_builder()
+        .on_error(on_sub_error)
+        .subscribe(room_queries(player.room_id));
+    *current = Some((player.room_id, handle));
+}
+
+/// Register all the callbacks our app will use to respond to database events.
+fn register_callbacks(ctx: &DbConnection) {
+    ctx.reducers.on_player_command(|ctx, _cmd| push_reducer_error("player_command", &ctx.event.status));
+    ctx.reducers.on_set_name(|ctx, _name| push_reducer_error("set_name", &ctx.event.status));
+    ctx.reducers.on_send_message(|ctx, _channel, _text| push_reducer_error("send_message", &ctx.event.status));
+    ctx.reducers.on_join_team(|ctx, _team_id| push_reducer_error("join_team", &ctx.event.status));
+    ctx.reducers.on_create_room(|ctx, _name, _max_players| push_reducer_error("create_room", &ctx.event.status));
+    ctx.reducers.on_join_room(|ctx, _room_id| push_reducer_error("join_room", &ctx.event.status));
+    ctx.reducers.on_leave_room(|ctx| push_reducer_error("leave_room", &ctx.event.status));
+    ctx.reducers.on_place_block(|ctx, _x, _y, _z, _block| push_reducer_error("place_block", &ctx.event.status));
+    ctx.reducers.on_break_block(|ctx, _x, _y, _z| push_reducer_error("break_block", &ctx.event.status));
+    ctx.reducers.on_move_item(|ctx, _from, _to| push_reducer_error("move_item", &ctx.event.status));
+    ctx.reducers.on_split_item(|ctx, _from, _to, _quantity| push_reducer_error("split_item", &ctx.event.status));
+    ctx.reducers.on_drop_item(|ctx, _slot, _quantity| push_reducer_error("drop_item", &ctx.event.status));
+    ctx.reducers.on_use_item(|ctx, _slot| push_reducer_error("use_item", &ctx.event.status));
+    ctx.reducers.on_craft(|ctx, _recipe_id| push_reducer_error("craft", &ctx.event.status));
+    ctx.reducers.on_propose_trade(|ctx, _partner| push_reducer_error("propose_trade", &ctx.event.status));
+    ctx.reducers.on_update_offer(|ctx, _trade_id, _offer, _coins| push_reducer_error("update_offer", &ctx.event.status));
+    ctx.reducers.on_accept_trade(|ctx, _trade_id| push_reducer_error("accept_trade", &ctx.event.status));
+    ctx.reducers.on_cancel_trade(|ctx, _trade_id| push_reducer_error("cancel_trade", &ctx.event.status));
+    ctx.reducers.on_transfer(|ctx, _to, _amount| push_reducer_error("transfer", &ctx.event.status));
+
+    ctx.db.chat_message().on_insert(on_chat_message_inserted);
+    ctx.db.proximity_message().on_insert(on_proximity_message_inserted);
+    ctx.db.transform().on_update(on_transform_updated);
+    ctx.db.transform().on_insert(update_chunk_view);
+    ctx.db.chunk().on_insert(on_chunk_changed);
+    ctx.db.chunk().on_update(|ctx, _old, new| on_chunk_changed(ctx, new));
+    ctx.db.inventory_slot().on_insert(|ctx, s| on_inventory_changed(ctx, &[s.slot], s.owner));
+    ctx.db.inventory_slot().on_update(|ctx, old, new| on_inventory_changed(ctx, &[old.slot, new.slot], new.owner));
+    ctx.db.inventory_slot().on_delete(|ctx, s| on_inventory_changed(ctx, &[s.slot], s.owner));
+    ctx.db.player().on_insert(on_player_changed);
+    ctx.db.player().on_update(|ctx, _old, new| on_player_changed(ctx, new));
+}
+
 /// Load credentials from a file and connect to the database.
-fn connect_to_db() -> DbConnection {
-    let token: Option<&str> = None;
-    DbConnection::builder()
+fn connect_to_db(resume: bool) -> DbConnection {
+    let token = if resume {
+        creds_store().load().unwrap_or_else(|e| {
+            eprintln!("Failed to load credentials: {:?}", e);
+            None
+        })
+    } else {
+        None
+    };
+    let conn = DbConnection::builder()
         // Register our `on_connect` callback, which will save our auth token.
         .on_connect(on_connected)
         // Register our `on_connect_error` callback, which will print a message, then exit the process.
@@ -50,7 +1434,6 @@ fn connect_to_db() -> DbConnection {
         // If the user has previously connected, we'll have saved a token in the `on_connect` callback.
         // In that case, we'll load it and pass it to `with_token`,
         // so we can re-authenticate as the same `Identity`.
-        // .with_token(creds_store().load().expect("Error loading credentials"))
         .with_token(token)
         // Set the database name we chose when we called `spacetime publish`.
         .with_module_name(DB_NAME)
@@ -58,18 +1441,26 @@ fn connect_to_db() -> DbConnection {
         .with_uri(HOST)
         // Finalize configuration and connect!
         .build()
-        .expect("Failed to connect")
+        .expect("Failed to connect");
+    register_callbacks(&conn);
+    subscribe_to_tables(&conn);
+    conn
 }
 
 fn creds_store() -> credentials::File {
     credentials::File::new(DB_NAME)
 }
 
-/// Our `on_connect` callback: save our credentials to a file.
-fn on_connected(_ctx: &DbConnection, _identity: Identity, token: &str) {
+/// Our `on_connect` callback: save our credentials to a file and send any name chosen before connecting.
+fn on_connected(ctx: &DbConnection, _identity: Identity, token: &str) {
     if let Err(e) = creds_store().save(token) {
         eprintln!("Failed to save credentials: {:?}", e);
     }
+    if let Some(name) = PENDING_NAME.lock().unwrap().take()
+        && let Err(e) = ctx.reducers.set_name(name)
+    {
+        eprintln!("Failed to send name: {:?}", e);
+    }
 }
 
 /// Our `on_connect_error` callback: print the error, then exit the process.
@@ -125,21 +1516,39 @@ fn on_disconnected(_ctx: &ErrorContext, err: Option<Error>) {
 //     // ctx.reducers.on_send_message(on_message_sent);
 // }
 
-// fn on_sub_applied(ctx: &SubscriptionEventContext) {
-//     println!("Fully connected and all subscriptions applied.");
-// }
+fn on_sub_applied(_ctx: &SubscriptionEventContext) {
+    println!("Fully connected and all subscriptions applied.");
+}
 
-// fn on_sub_error(_ctx: &ErrorContext, err: Error) {
-//     eprintln!("Subscription failed: {}", err);
-//     std::process::exit(1);
-// }
+fn on_sub_error(_ctx: &ErrorContext, err: Error) {
+    eprintln!("Subscription failed: {}", err);
+    std::process::exit(1);
+}
 
-// /// Register subscriptions for all rows of both tables.
-// fn subscribe_to_tables(ctx: &DbConnection) {
-//     ctx.subscription_builder()
-//         .on_applied(on_sub_applied)
-//         .on_error(on_sub_error)
-//         .subscribe(["SELECT * FROM player"]);
-// }
+/// Register subscriptions for the tables the game reads.
+/// Only the room list and our own player are global, the rest follows our room (see `on_player_changed`).
+fn subscribe_to_tables(ctx: &DbConnection) {
+    ctx.subscription_builder()
+        .on_applied(on_sub_applied)
+        .on_error(on_sub_error)
+        .subscribe([
+            "SELECT * FROM room",
+            "SELECT * FROM world_config",
+            "SELECT * FROM world_clock",
+            "SELECT * FROM player WHERE identity = :sender",
+            "SELECT * FROM player_stats WHERE identity = :sender",
+            "SELECT * FROM leaderboard",
+            "SELECT * FROM item_definition",
+            "SELECT * FROM inventory_slot WHERE owner = :sender",
+            "SELECT * FROM recipe",
+            "SELECT * FROM crafting_station",
+            "SELECT * FROM craft_job WHERE owner = :sender",
+            "SELECT * FROM resource_node",
+            "SELECT * FROM trade WHERE proposer = :sender",
+            "SELECT * FROM trade WHERE partner = :sender",
+            "SELECT * FROM wallet WHERE identity = :sender",
+            "SELECT * FROM ledger_entry WHERE identity = :sender",
+        ]);
+}
 
 